@@ -117,7 +117,7 @@ macro_rules! impl_nums {
     };
 }
 
-impl<'de, 'a, R> serde::Deserializer<'de> for &'a mut Deserializer<R>
+impl<'de, R> serde::Deserializer<'de> for &mut Deserializer<R>
 where
     R: EchonetLiteRead<'de>,
 {
@@ -211,23 +211,6 @@ where
     where
         V: serde::de::Visitor<'de>,
     {
-        impl<'de, 'a, R: 'a> serde::de::EnumAccess<'de> for &'a mut Deserializer<R>
-        where
-            R: EchonetLiteRead<'de>,
-        {
-            type Error = Error;
-            type Variant = Self;
-
-            fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant)>
-            where
-                V: serde::de::DeserializeSeed<'de>,
-            {
-                let idx: u32 = serde::de::Deserialize::deserialize(&mut *self)?;
-                let val: Result<_> = seed.deserialize(idx.into_deserializer());
-                Ok((val?, self))
-            }
-        }
-
         visitor.visit_enum(self)
     }
 
@@ -240,7 +223,7 @@ where
             len: usize,
         }
 
-        impl<'de, 'a, 'b: 'a, R: EchonetLiteRead<'de> + 'b> serde::de::SeqAccess<'de> for Access<'a, R> {
+        impl<'de, 'a, R: EchonetLiteRead<'de> + 'a> serde::de::SeqAccess<'de> for Access<'a, R> {
             type Error = Error;
 
             fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
@@ -383,7 +366,24 @@ where
     }
 }
 
-impl<'de, 'a, R> serde::de::VariantAccess<'de> for &'a mut Deserializer<R>
+impl<'de, R> serde::de::EnumAccess<'de> for &mut Deserializer<R>
+where
+    R: EchonetLiteRead<'de>,
+{
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant)>
+    where
+        V: serde::de::DeserializeSeed<'de>,
+    {
+        let idx: u32 = serde::de::Deserialize::deserialize(&mut *self)?;
+        let val: Result<_> = seed.deserialize(idx.into_deserializer());
+        Ok((val?, self))
+    }
+}
+
+impl<'de, R> serde::de::VariantAccess<'de> for &mut Deserializer<R>
 where
     R: EchonetLiteRead<'de>,
 {
@@ -3,12 +3,19 @@ use crate::lib::fmt;
 use crate::lib::ops::{Deref, DerefMut};
 use crate::lib::vec::Vec;
 use num_derive::FromPrimitive;
-use serde::{Deserialize, Serialize};
+use serde::de::{SeqAccess, Unexpected, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 pub use crate::object::EchonetObject;
 use crate::{de, ser, Error};
 
+/// ECHONET Lite message header 1.
+const EHD1: u8 = 0x10;
+/// ECHONET Lite message header 2 of the specified message format.
+const EHD2: u8 = 0x81;
+
 /// An ECHONET Lite packet representation.
 ///
 /// ECHONET Lite SPEC shows an ECHONET Lite packet contains
@@ -19,7 +26,10 @@ use crate::{de, ser, Error};
 /// - ESV: ECHONET Lite service
 /// - OPC: Number of processing properties
 /// - (EPC, PDC, EDT) * OPC
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// SetGet and its responses have the properties to write and then the properties to read,
+/// each of them preceded by its OPC.
+#[derive(Debug, Clone, PartialEq)]
 pub struct ElPacket {
     // ECHONTE Lite header must be 0x1081
     ehd1: u8,
@@ -33,25 +43,54 @@ pub struct ElPacket {
     // ECHONET service code
     pub esv: ServiceCode,
     // properties contain opc (Operation count), epc (ECHONET property code), and
-    // edt (ECHONET data).
+    // edt (ECHONET data). SetGet writes these properties.
     pub props: Properties,
+    // Properties to read for SetGet, empty for the other services.
+    pub get_props: Properties,
 }
 
 impl ElPacket {
+    /// Returns the transaction ID, which is copied into the response.
+    pub fn transaction_id(&self) -> u16 {
+        self.transaction_id
+    }
+
     /// Serializes an ECHONET Lite packet into byte array.
     pub fn serialize(&self) -> Result<Vec<u8>, Error> {
         ser::serialize(&self)
     }
 
     /// Deserializes an ECHONET Lite packet from byte array.
+    ///
+    /// Returns an error unless the header is 0x1081, the specified message format.
     pub fn from_bytes(bytes: &[u8]) -> Result<(usize, ElPacket), Error> {
         de::deserialize(bytes)
     }
 
     /// Returns whether `self` is a response for the `req`.
+    ///
+    /// If `req` is sent to instance 0, the responses of any instance of the class match.
     #[allow(clippy::suspicious_operation_groupings)]
     pub fn is_response_for(&self, req: &ElPacket) -> bool {
-        self.transaction_id == req.transaction_id && self.seoj == req.deoj
+        let seoj = if req.deoj.instance() == 0 {
+            self.seoj.class_code() == req.deoj.class_code()
+        } else {
+            self.seoj == req.deoj
+        };
+        self.transaction_id == req.transaction_id && seoj
+    }
+
+    /// Returns the device side object of the exchange.
+    ///
+    /// Requests are sent to the device, so the device is the destination object.
+    /// Responses and notifications are sent by the device, so the device is the source object.
+    /// The exception is InfCRes, which is sent by the controller to the device.
+    pub fn device_object(&self) -> EchonetObject {
+        if self.esv.is_request() || self.esv == ServiceCode::InfCRes {
+            self.deoj
+        } else {
+            self.seoj
+        }
     }
 
     /// Creates a new response for itself.
@@ -72,6 +111,91 @@ impl ElPacket {
     }
 }
 
+const FIELDS: &[&str] = &[
+    "ehd1",
+    "ehd2",
+    "transaction_id",
+    "seoj",
+    "deoj",
+    "esv",
+    "props",
+    "get_props",
+];
+
+impl Serialize for ElPacket {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ElPacket", FIELDS.len())?;
+        state.serialize_field("ehd1", &self.ehd1)?;
+        state.serialize_field("ehd2", &self.ehd2)?;
+        state.serialize_field("transaction_id", &self.transaction_id)?;
+        state.serialize_field("seoj", &self.seoj)?;
+        state.serialize_field("deoj", &self.deoj)?;
+        state.serialize_field("esv", &self.esv)?;
+        state.serialize_field("props", &self.props)?;
+        if self.esv.is_set_get() {
+            state.serialize_field("get_props", &self.get_props)?;
+        } else {
+            state.skip_field("get_props")?;
+        }
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ElPacket {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("ElPacket", FIELDS, ElPacketVisitor)
+    }
+}
+
+struct ElPacketVisitor;
+
+impl<'de> Visitor<'de> for ElPacketVisitor {
+    type Value = ElPacket;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an ECHONET Lite packet")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ElPacket, A::Error> {
+        macro_rules! next {
+            ($index:expr) => {
+                seq.next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length($index, &self))?
+            };
+        }
+        let ehd1: u8 = next!(0);
+        let ehd2: u8 = next!(1);
+        // other headers are not ECHONET Lite or not the specified message format
+        if ehd1 != EHD1 || ehd2 != EHD2 {
+            let ehd = u64::from(ehd1) << 8 | u64::from(ehd2);
+            return Err(serde::de::Error::invalid_value(
+                Unexpected::Unsigned(ehd),
+                &"EHD 0x1081",
+            ));
+        }
+        let transaction_id = next!(2);
+        let seoj = next!(3);
+        let deoj = next!(4);
+        let esv: ServiceCode = next!(5);
+        let props = next!(6);
+        let get_props = if esv.is_set_get() {
+            next!(7)
+        } else {
+            Properties::default()
+        };
+        Ok(ElPacket {
+            ehd1,
+            ehd2,
+            transaction_id,
+            seoj,
+            deoj,
+            esv,
+            props,
+            get_props,
+        })
+    }
+}
+
 impl fmt::Display for ElPacket {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "EHD: {:02X}{:02X}", self.ehd1, self.ehd2)?;
@@ -79,7 +203,14 @@ impl fmt::Display for ElPacket {
         writeln!(f, "SEOJ: {}", self.seoj)?;
         writeln!(f, "DEOJ: {}", self.deoj)?;
         writeln!(f, "{}", self.esv)?;
-        write!(f, "{}", self.props)
+        if self.esv.is_set_get() {
+            writeln!(f, "Set:")?;
+            write!(f, "{}", self.props)?;
+            writeln!(f, "Get:")?;
+            write!(f, "{}", self.get_props)
+        } else {
+            write!(f, "{}", self.props)
+        }
     }
 }
 
@@ -122,6 +253,22 @@ pub enum ServiceCode {
     SetGetRes = 0x7E,
 }
 
+impl ServiceCode {
+    /// Returns whether the service code is a request (SetI, SetC, Get, InfReq or SetGet).
+    pub fn is_request(self) -> bool {
+        (self as u8) & 0xF0 == 0x60
+    }
+
+    /// Returns whether the service code is SetGet or one of its responses,
+    /// which have both properties to write and to read.
+    pub fn is_set_get(self) -> bool {
+        matches!(
+            self,
+            ServiceCode::SetGet | ServiceCode::SetGetRes | ServiceCode::SetGetSNA
+        )
+    }
+}
+
 impl fmt::Display for ServiceCode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "ESV: {:02X} ", *self as u8)?;
@@ -163,7 +310,7 @@ impl Properties {
         }
     }
 
-    pub fn iter(&self) -> core::slice::Iter<Property> {
+    pub fn iter(&self) -> core::slice::Iter<'_, Property> {
         self.0.iter()
     }
 }
@@ -314,6 +461,7 @@ pub struct ElPacketBuilder {
     deoj: EchonetObject,
     esv: Option<ServiceCode>,
     props: Properties,
+    get_props: Properties,
 }
 
 impl Default for ElPacketBuilder {
@@ -330,6 +478,7 @@ impl ElPacketBuilder {
             deoj: Default::default(),
             esv: None,
             props: Default::default(),
+            get_props: Default::default(),
         }
     }
 
@@ -364,15 +513,22 @@ impl ElPacketBuilder {
         self
     }
 
+    /// Properties to read for SetGet.
+    pub fn get_props(mut self, props: Properties) -> Self {
+        self.get_props = props;
+        self
+    }
+
     pub fn build(self) -> ElPacket {
         ElPacket {
-            ehd1: 0x10,
-            ehd2: 0x81,
+            ehd1: EHD1,
+            ehd2: EHD2,
             transaction_id: self.transaction_id,
             seoj: self.seoj,
             deoj: self.deoj,
             esv: self.esv.unwrap(), // TODO: define error
             props: self.props,
+            get_props: self.get_props,
         }
     }
 }
@@ -389,10 +545,7 @@ impl ElPacketBuilder {
 macro_rules! prop {
     ( $epc:expr, [ $( $edt:expr ),* ] ) => {
         {
-            let mut bytes: Vec<u8> = Vec::new();
-            $(
-                bytes.push($edt);
-            )*
+            let bytes: Vec<u8> = vec![$( $edt ),*];
             Property{ epc: $epc, edt: Edt::new(bytes) }
         }
     };
@@ -463,6 +616,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn response_for_wildcard() {
+        let request = |deoj| {
+            ElPacketBuilder::new()
+                .transaction_id(1)
+                .esv(ServiceCode::Get)
+                .seoj([0x05, 0xFF, 0x01])
+                .deoj(deoj)
+                .props(props!([0x80, []]))
+                .build()
+        };
+        let response = request([0x01, 0x30, 0x02])
+            .create_response(ServiceCode::GetRes, props!([0x80, [0x30]]));
+        assert!(response.is_response_for(&request([0x01, 0x30, 0x02])));
+        assert!(!response.is_response_for(&request([0x01, 0x30, 0x01])));
+        assert!(response.is_response_for(&request([0x01, 0x30, 0x00])));
+        assert!(!response.is_response_for(&request([0x01, 0x35, 0x00])));
+    }
+
     #[test]
     fn deserialize() {
         let input: Vec<u8> = vec![
@@ -486,6 +658,34 @@ mod test {
         assert_eq!(expect, decoded);
     }
 
+    #[test]
+    fn deserialize_invalid_header() {
+        let mut input: Vec<u8> = vec![
+            0x10, 0x82, 0, 1, 0xef, 0xff, 0x01, 0x03, 0x08, 0x01, 0x62, 1, 0x80, 0x01, 0x02,
+        ];
+        assert!(ElPacket::from_bytes(&input).is_err());
+        input[0..2].copy_from_slice(&[0x20, 0x81]);
+        assert!(ElPacket::from_bytes(&input).is_err());
+    }
+
+    #[test]
+    fn set_get() {
+        let packet = ElPacketBuilder::new()
+            .transaction_id(2)
+            .esv(ServiceCode::SetGet)
+            .seoj([0x05, 0xFF, 0x01])
+            .deoj([0x01, 0x30, 0x01])
+            .props(props!([0x80, [0x30]]))
+            .get_props(bulk_read!(0xB0, 0xB3))
+            .build();
+        let bytes = packet.serialize().unwrap();
+        assert_eq!(
+            bytes[10..],
+            [0x6E, 1, 0x80, 0x01, 0x30, 2, 0xB0, 0x00, 0xB3, 0x00]
+        );
+        assert_eq!(ElPacket::from_bytes(&bytes).unwrap(), (bytes.len(), packet));
+    }
+
     #[test]
     fn deserialize_tid() {
         let input = [0u8, 1u8];
@@ -33,6 +33,24 @@ pub enum ErrorKind {
     SizeLimit,
     /// echonet-lite-rs can not encode sequences of unknown length (like iterators).
     SequenceMustHaveLength,
+    /// Returned if a packet is converted into a class specific packet of another class.
+    ClassMismatch {
+        /// The class code of the class specific packet.
+        expected: [u8; 2],
+        /// The class code of the device object in the packet.
+        found: [u8; 2],
+    },
+    /// Returned if a request accesses a property in a way its access rule does not allow.
+    AccessDenied {
+        /// The class code of the device object.
+        class: [u8; 2],
+        /// The property code.
+        epc: u8,
+    },
+    /// Returned if the machine readable appendix (MRA) data can not be loaded.
+    InvalidMra(String),
+    /// Returned if a string is not a valid notation of a class code or an ECHONET object.
+    InvalidNotation,
     /// A custom error message from Serde.
     Custom(String),
 }
@@ -43,6 +61,20 @@ impl From<io::Error> for Error {
     }
 }
 
+impl ErrorKind {
+    /// Returns true if a blocking receive timed out.
+    #[cfg(feature = "std")]
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            ErrorKind::Io(err) => matches!(
+                err.kind(),
+                io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+            ),
+            _ => false,
+        }
+    }
+}
+
 impl fmt::Display for ErrorKind {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -65,6 +97,18 @@ impl fmt::Display for ErrorKind {
                     "EchonetLite-rs does not support the serde::Deserializer::deserialize_any method"
                 )
             }
+            ErrorKind::ClassMismatch { expected, found } => write!(
+                fmt,
+                "ClassMismatch, expected {:02X}{:02X}, found {:02X}{:02X}",
+                expected[0], expected[1], found[0], found[1]
+            ),
+            ErrorKind::AccessDenied { class, epc } => write!(
+                fmt,
+                "AccessDenied, EPC {:02X} of class {:02X}{:02X}",
+                epc, class[0], class[1]
+            ),
+            ErrorKind::InvalidMra(ref s) => write!(fmt, "InvalidMra: {s}"),
+            ErrorKind::InvalidNotation => write!(fmt, "InvalidNotation"),
             ErrorKind::Custom(ref s) => s.fmt(fmt),
         }
     }
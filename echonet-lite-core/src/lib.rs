@@ -1,15 +1,15 @@
 //! This crate is pure Rust ECHONET Lite implementation including
 //! - serde implementation of ECHONET Lite packet
 //! - detailed property configurations of ECHONET Device objects (WIP)
-//!
-//! but not included
-//! - transport layer (usually, UDP with IPv4/IPv6) implementation
-//! - specific ECHONET Lite object behavior
+//! - loading the machine readable appendix (MRA) at runtime (`mra` feature)
+//! - blocking UDP transport over IPv4 and IPv6 multicast (`transport` feature)
+//! - tokio codec, UDP socket, controller client and object server (`async` feature)
 
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
 
 /// All the types we need from `std`, `core`, and `alloc` crates.
+#[allow(unused_imports)]
 mod lib {
     mod core {
         #[cfg(not(feature = "std"))]
@@ -40,6 +40,11 @@ mod lib {
     #[cfg(feature = "std")]
     pub use std::boxed::Box;
 
+    #[cfg(not(feature = "std"))]
+    pub use alloc::collections::BTreeMap;
+    #[cfg(feature = "std")]
+    pub use std::collections::BTreeMap;
+
     #[cfg(feature = "std")]
     pub use std::error;
 
@@ -55,7 +60,11 @@ mod de;
 mod ser;
 pub use de::deserialize;
 pub use ser::serialize;
+#[cfg(feature = "mra")]
+pub mod mra;
 pub mod object;
 pub mod prelude;
+#[cfg(feature = "transport")]
+pub mod transport;
 pub use el_packet::*;
 pub use error::{Error, ErrorKind, Result};
@@ -0,0 +1,296 @@
+use super::resolve::invalid;
+use super::{ClassKind, Definitions, MraClass, Property, TypeDef, ValidRelease};
+use crate::error::Result;
+use crate::object::{
+    Access, AccessRule, ClassDef, ClassPacket, EdtDef, LocalizedName, NumberDef, PropertyDef,
+    PropertyMap, ReleaseRange,
+};
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Contents of an `mraData` directory with all the references resolved.
+#[derive(Debug, Clone)]
+pub struct MraData {
+    pub definitions: Definitions,
+    pub super_class: MraClass,
+    pub node_profile: MraClass,
+    pub devices: Vec<MraClass>,
+}
+
+impl MraData {
+    /// Reads `definitions`, `superClass`, `nodeProfile` and `devices` of `mra_data`.
+    pub fn load<P: AsRef<Path>>(mra_data: P) -> Result<Self> {
+        let mra_data = mra_data.as_ref();
+        let definitions: Definitions =
+            read_json(&mra_data.join("definitions").join("definitions.json"))?;
+        let read_dir = |dir: &str| -> Result<Vec<MraClass>> {
+            let mut paths = Vec::new();
+            for entry in fs::read_dir(mra_data.join(dir))? {
+                let path = entry?.path();
+                if path.extension().is_some_and(|ext| ext == "json") {
+                    paths.push(path);
+                }
+            }
+            paths.sort();
+            paths
+                .iter()
+                .map(|path| definitions.resolve_class(read_json(path)?))
+                .collect()
+        };
+        let single = |dir: &str, kind: ClassKind| -> Result<MraClass> {
+            let class = read_dir(dir)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| invalid(format!("no class in {dir} directory")))?;
+            if class.kind() != kind {
+                return Err(invalid(format!("unexpected class {} in {dir}", class.eoj)));
+            }
+            Ok(class)
+        };
+
+        Ok(Self {
+            super_class: single("superClass", ClassKind::SuperClass)?,
+            node_profile: single("nodeProfile", ClassKind::NodeProfile)?,
+            devices: read_dir("devices")?,
+            definitions,
+        })
+    }
+}
+
+/// Reads a JSON file of the MRA.
+pub fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let contents = fs::read_to_string(path)?;
+    serde_json::from_str(&contents).map_err(|err| invalid(format!("{}: {}", path.display(), err)))
+}
+
+/// Class definitions loaded from the MRA at runtime.
+///
+/// The definitions can be registered to a [`ClassRegistry`](crate::object::ClassRegistry)
+/// and used in the same way as the built-in ones, e.g. for names, value decoding and access rules.
+/// They live until the end of the program, so a database should be loaded only once.
+///
+/// # Examples
+///
+/// ```no_run
+/// use echonet_lite::mra::ClassDatabase;
+/// use echonet_lite::object::ClassRegistry;
+///
+/// let database = ClassDatabase::load("MRA_V1.1.1/mraData").unwrap();
+/// let mut registry = ClassRegistry::default();
+/// registry.extend(database.iter());
+/// ```
+#[derive(Debug, Default)]
+pub struct ClassDatabase {
+    classes: Vec<&'static ClassDef>,
+}
+
+impl ClassDatabase {
+    /// Loads the node profile and the device classes of an `mraData` directory.
+    pub fn load<P: AsRef<Path>>(mra_data: P) -> Result<Self> {
+        Self::from_mra_data(&MraData::load(mra_data)?)
+    }
+
+    /// Creates class definitions of the node profile and the devices.
+    ///
+    /// The super class properties are added to the device classes.
+    pub fn from_mra_data(data: &MraData) -> Result<Self> {
+        let mut classes = vec![class_def(&data.node_profile, None)?];
+        for device in &data.devices {
+            classes.push(class_def(device, Some(&data.super_class))?);
+        }
+        Ok(Self { classes })
+    }
+
+    /// Finds the class definition for `code`.
+    pub fn get(&self, code: [u8; 2]) -> Option<&'static ClassDef> {
+        self.classes
+            .iter()
+            .copied()
+            .find(|class| class.code == code)
+    }
+
+    /// Iterates over all the loaded class definitions.
+    pub fn iter(&self) -> impl Iterator<Item = &'static ClassDef> + '_ {
+        self.classes.iter().copied()
+    }
+}
+
+fn class_def(class: &MraClass, super_class: Option<&MraClass>) -> Result<&'static ClassDef> {
+    let code = u16::from_str_radix(class.eoj.trim_start_matches("0x"), 16)
+        .map_err(|_| invalid(format!("invalid class code {}", class.eoj)))?;
+
+    let mut properties = BTreeMap::new();
+    for prop in super_class
+        .into_iter()
+        .flat_map(MraClass::latest_properties)
+        .chain(class.latest_properties())
+    {
+        let (epc, def) = property_def(prop)?;
+        properties.insert(epc, def);
+    }
+
+    let class = ClassDef {
+        code: code.to_be_bytes(),
+        name: localized(&class.class_name.ja, &class.class_name.en),
+        short_name: leak(&class.class_name.en),
+        properties: PropertyMap::Dynamic(properties),
+        releases: release_range(&class.valid_release),
+        decode: ClassPacket::custom,
+    };
+    Ok(Box::leak(Box::new(class)))
+}
+
+fn property_def(prop: &Property) -> Result<(u8, PropertyDef)> {
+    let epc = u8::from_str_radix(prop.epc.trim_start_matches("0x"), 16)
+        .map_err(|_| invalid(format!("invalid EPC {}", prop.epc)))?;
+
+    let mut def = PropertyDef::new(leak(&prop.name.ja), leak(&prop.name.en))
+        .releases(release_range(&prop.valid_release))
+        .access(AccessRule::new(
+            access(&prop.access_rule.get),
+            access(&prop.access_rule.set),
+            access(&prop.access_rule.inf),
+        ));
+    def.edt = edt_def(&prop.data);
+    Ok((epc, def))
+}
+
+fn edt_def(data: &TypeDef) -> EdtDef {
+    match data {
+        TypeDef::Inline(def) if def.size == 1 => {
+            let states: Vec<_> = def
+                .enumeration
+                .iter()
+                .filter_map(|state| {
+                    let edt = u8::from_str_radix(state.edt.trim_start_matches("0x"), 16).ok()?;
+                    Some((
+                        edt,
+                        localized(&state.descriptions.ja, &state.descriptions.en),
+                    ))
+                })
+                .collect();
+            EdtDef::State(Box::leak(states.into_boxed_slice()))
+        }
+        TypeDef::Number(def) => {
+            let exponent = def.multiple.map_or(0, |m| m.log10().round() as i8);
+            let unit = leak(def.unit.as_deref().unwrap_or_default());
+            if def.signed() {
+                EdtDef::Number(NumberDef::signed(def.size(), exponent, unit))
+            } else {
+                EdtDef::Number(NumberDef::unsigned(def.size(), exponent, unit))
+            }
+        }
+        _ => EdtDef::Raw,
+    }
+}
+
+fn access(access: &str) -> Access {
+    match access {
+        "notApplicable" => Access::NotApplicable,
+        "optional" => Access::Optional,
+        // including conditionally required ones
+        _ => Access::Required,
+    }
+}
+
+fn release_range(release: &ValidRelease) -> ReleaseRange {
+    let letter = |release: &str| release.bytes().next().unwrap_or(b'A');
+    if release.to == "latest" {
+        ReleaseRange::since(letter(&release.from))
+    } else {
+        ReleaseRange::new(letter(&release.from), letter(&release.to))
+    }
+}
+
+fn localized(ja: &str, en: &str) -> LocalizedName {
+    LocalizedName::new(leak(ja), leak(en))
+}
+
+fn leak(s: &str) -> &'static str {
+    Box::leak(s.to_string().into_boxed_str())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ClassDatabase, Definitions, MraClass, MraData};
+    use crate::object::{ClassCode, ClassRegistry, Release};
+    use crate::{props, Edt, Properties, Property, ServiceCode};
+
+    fn class(eoj: &str, short_name: &str, props: &str) -> MraClass {
+        serde_json::from_str(&format!(
+            r#"{{ "eoj": "{eoj}", "validRelease": {{ "from": "C", "to": "latest" }},
+                "className": {{ "ja": "{short_name}", "en": "{short_name}" }},
+                "shortName": "{short_name}", "elProperties": [{props}] }}"#
+        ))
+        .unwrap()
+    }
+
+    fn prop(epc: &str, en: &str, set: &str, data: &str) -> String {
+        format!(
+            r#"{{ "epc": "{epc}", "validRelease": {{ "from": "A", "to": "latest" }},
+                "propertyName": {{ "ja": "{en}", "en": "{en}" }}, "shortName": "{en}",
+                "accessRule": {{ "get": "required", "set": "{set}", "inf": "optional" }},
+                "descriptions": {{ "ja": "", "en": "" }}, "data": {data} }}"#
+        )
+    }
+
+    #[test]
+    fn decode_with_loaded_class() {
+        let definitions: Definitions = serde_json::from_str(
+            r##"{ "definitions": {
+                "number_W": { "type": "number", "format": "int32", "unit": "W" },
+                "state_ON-OFF_3031": { "type": "state", "size": 1, "enum": [
+                    { "edt": "0x30", "name": "true", "descriptions": { "ja": "入", "en": "ON" } },
+                    { "edt": "0x31", "name": "false", "descriptions": { "ja": "切", "en": "OFF" } }
+                ] }
+            } }"##,
+        )
+        .unwrap();
+        let on_off = r##"{ "$ref": "#/definitions/state_ON-OFF_3031" }"##;
+        let power = r##"{ "$ref": "#/definitions/number_W" }"##;
+        let resolve = |class| definitions.resolve_class(class).unwrap();
+        let data = MraData {
+            super_class: resolve(class(
+                "0x0000",
+                "superClass",
+                &prop("0x80", "Operation status", "optional", on_off),
+            )),
+            node_profile: resolve(class("0x0EF0", "nodeProfile", "")),
+            devices: vec![resolve(class(
+                "0x02F3",
+                "newMeter",
+                &prop("0xE7", "Power", "notApplicable", power),
+            ))],
+            definitions: definitions.clone(),
+        };
+
+        let database = ClassDatabase::from_mra_data(&data).unwrap();
+        let meter = database.get([0x02, 0xF3]).unwrap();
+        assert_eq!(meter.name.en, "newMeter");
+        assert_eq!(meter.properties.len(), 2);
+
+        let mut registry = ClassRegistry::empty();
+        registry.extend(database.iter());
+        let packet = registry.decode(
+            [0x02, 0xF3, 0x01].into(),
+            props!([0x80, [0x31]], [0xE7, [0xFF, 0xFF, 0xFF, 0x9C]]),
+        );
+        let display = format!("{}", packet.localized(crate::object::Locale::En));
+        assert!(display.contains("[Operation status] OFF"), "{display}");
+        assert!(display.contains("[Power] -100 W"), "{display}");
+
+        let class = ClassCode::new(0x02, 0xF3);
+        assert!(registry.is_allowed(class, ServiceCode::SetC, 0x80));
+        assert!(!registry.is_allowed(class, ServiceCode::SetC, 0xE7));
+        assert_eq!(
+            registry.is_valid_epc(class, 0xE7, Release::new(b'B')),
+            Some(false)
+        );
+        assert_eq!(
+            registry.is_valid_epc(class, 0xE7, Release::new(b'C')),
+            Some(true)
+        );
+    }
+}
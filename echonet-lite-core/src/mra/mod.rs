@@ -0,0 +1,374 @@
+//! Model of the machine readable appendix (MRA) JSON published by ECHONET Consortium,
+//! and a class database loaded from it at runtime.
+//!
+//! <https://echonet.jp/spec_mra_rp1/>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub use database::*;
+
+mod database;
+mod resolve;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ValidRelease {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct JaEnString {
+    pub ja: String,
+    pub en: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AccessRule {
+    pub get: String,
+    pub set: String,
+    pub inf: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DefRef {
+    #[serde(rename = "$ref")]
+    pub def: String,
+    /// EPCs of the properties whose values multiply this one.
+    #[serde(default)]
+    pub coefficient: Vec<String>,
+    #[serde(rename = "overflowCode", default)]
+    pub overflow_code: bool,
+    #[serde(rename = "underflowCode", default)]
+    pub underflow_code: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EdtVariant {
+    pub edt: String,
+    pub name: String,
+    pub descriptions: JaEnString,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DefInline {
+    #[serde(rename = "type")]
+    pub t: String,
+    pub size: usize,
+    #[serde(rename = "enum")]
+    pub enumeration: Vec<EdtVariant>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DefOneOf {
+    #[serde(rename = "oneOf")]
+    pub one_of: Vec<TypeDef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DefElement {
+    #[serde(rename = "shortName")]
+    pub short_name: String,
+    pub element: TypeDef,
+    #[serde(rename = "elementName")]
+    pub name: JaEnString,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DefObject {
+    #[serde(rename = "type")]
+    pub t: String,
+    pub properties: Vec<DefElement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DefBitMapPosition {
+    pub index: usize,
+    #[serde(rename = "bitMask")]
+    pub bitmask: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DefBitMaps {
+    pub name: String,
+    pub descriptions: JaEnString,
+    pub position: DefBitMapPosition,
+    pub value: TypeDef,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DefBitMapType {
+    #[serde(rename = "type")]
+    pub t: String,
+    pub size: usize,
+    pub bitmaps: Vec<DefBitMaps>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DefArray {
+    #[serde(rename = "type")]
+    pub t: String,
+    #[serde(rename = "itemSize")]
+    pub item_size: usize,
+    #[serde(rename = "maxItems")]
+    pub max_items: usize,
+    pub items: TypeDef,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct NumericValue {
+    pub edt: String,
+    #[serde(rename = "numericValue")]
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DefNumeric {
+    #[serde(rename = "type")]
+    pub t: String,
+    pub size: usize,
+    #[serde(rename = "enum")]
+    pub enumerations: Vec<NumericValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DefNumber {
+    #[serde(rename = "type")]
+    pub t: String,
+    /// `int8`, `uint8`, `int16`, `uint16`, `int32` or `uint32`.
+    pub format: String,
+    pub minimum: Option<i64>,
+    pub maximum: Option<i64>,
+    pub unit: Option<String>,
+    #[serde(alias = "multipleOf")]
+    pub multiple: Option<f64>,
+    #[serde(default)]
+    pub coefficient: Vec<String>,
+    #[serde(rename = "overflowCode", default)]
+    pub overflow_code: bool,
+    #[serde(rename = "underflowCode", default)]
+    pub underflow_code: bool,
+}
+
+impl DefNumber {
+    /// Size of the EDT in bytes.
+    pub fn size(&self) -> usize {
+        match self.format.trim_start_matches('u') {
+            "int8" => 1,
+            "int16" => 2,
+            _ => 4,
+        }
+    }
+
+    pub fn signed(&self) -> bool {
+        !self.format.starts_with('u')
+    }
+
+    /// EDT reserved for values above the maximum.
+    pub fn overflow(&self) -> Option<i64> {
+        let bits = self.size() as u32 * 8;
+        let code = if self.signed() {
+            (1i64 << (bits - 1)) - 1
+        } else {
+            (1i64 << bits) - 1
+        };
+        self.overflow_code.then_some(code)
+    }
+
+    /// EDT reserved for values below the minimum.
+    pub fn underflow(&self) -> Option<i64> {
+        let bits = self.size() as u32 * 8;
+        let code = if self.signed() {
+            -(1i64 << (bits - 1))
+        } else {
+            (1i64 << bits) - 2
+        };
+        self.underflow_code.then_some(code)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DefRaw {
+    #[serde(rename = "type")]
+    pub t: String,
+    #[serde(rename = "minSize")]
+    pub min_size: usize,
+    #[serde(rename = "maxSize")]
+    pub max_size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DefLevel {
+    #[serde(rename = "type")]
+    pub t: String,
+    /// EDT of the lowest level.
+    pub base: String,
+    /// Number of levels.
+    pub maximum: u8,
+}
+
+/// `date-time`, `date` and `time` types.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DefDateTime {
+    #[serde(rename = "type")]
+    pub t: String,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum TypeDef {
+    Ref(DefRef),
+    Inline(DefInline),
+    OneOf(DefOneOf),
+    Object(DefObject),
+    BitMap(DefBitMapType),
+    // need Box to avoid recursive infinite size
+    Array(Box<DefArray>),
+    Numeric(DefNumeric),
+    Number(DefNumber),
+    Raw(DefRaw),
+    Level(DefLevel),
+    DateTime(DefDateTime),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Property {
+    pub epc: String,
+    #[serde(rename = "validRelease")]
+    pub valid_release: ValidRelease,
+    #[serde(rename = "propertyName")]
+    pub name: JaEnString,
+    #[serde(rename = "shortName")]
+    pub short_name: String,
+    #[serde(rename = "accessRule")]
+    pub access_rule: AccessRule,
+    pub descriptions: JaEnString,
+    pub data: TypeDef,
+}
+
+/// A class of `superClass`, `nodeProfile` or `devices`, which share the same format.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MraClass {
+    pub eoj: String,
+    #[serde(rename = "validRelease")]
+    pub valid_release: ValidRelease,
+    #[serde(rename = "className")]
+    pub class_name: JaEnString,
+    #[serde(rename = "shortName")]
+    pub short_name: String,
+    #[serde(rename = "elProperties")]
+    pub properties: Vec<Property>,
+}
+
+/// Contents of `definitions/definitions.json`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Definitions {
+    pub definitions: HashMap<String, TypeDef>,
+}
+
+impl ValidRelease {
+    /// Returns true if defined in all the releases.
+    pub fn is_all(&self) -> bool {
+        self.from == "A" && self.to == "latest"
+    }
+
+    /// Sort key of the last release, `latest` being the newest.
+    fn to_key(&self) -> (bool, usize, &str) {
+        (self.to == "latest", self.to.len(), &self.to)
+    }
+}
+
+/// Kinds of the classes in `mraData`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClassKind {
+    /// Device object super class (0x0000), whose properties every device class has.
+    SuperClass,
+    /// Node profile (0x0EF0).
+    NodeProfile,
+    Device,
+}
+
+impl MraClass {
+    /// Tells the kind from the class code.
+    pub fn kind(&self) -> ClassKind {
+        match self.eoj.to_ascii_uppercase().as_str() {
+            "0X0000" => ClassKind::SuperClass,
+            "0X0EF0" => ClassKind::NodeProfile,
+            _ => ClassKind::Device,
+        }
+    }
+
+    /// Properties with unique EPCs, keeping the definition of the newest release.
+    pub fn latest_properties(&self) -> Vec<&Property> {
+        let mut props: Vec<&Property> = Vec::new();
+        for prop in &self.properties {
+            match props.iter_mut().find(|p| p.epc == prop.epc) {
+                Some(p) if p.valid_release.to_key() <= prop.valid_release.to_key() => *p = prop,
+                Some(_) => {}
+                None => props.push(prop),
+            }
+        }
+        props
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn latest_properties() {
+        let prop = |epc: &str, to: &str| Property {
+            epc: epc.to_string(),
+            valid_release: ValidRelease {
+                from: "A".to_string(),
+                to: to.to_string(),
+            },
+            name: JaEnString {
+                ja: to.to_string(),
+                en: to.to_string(),
+            },
+            short_name: "test".to_string(),
+            access_rule: AccessRule {
+                get: "required".to_string(),
+                set: "notApplicable".to_string(),
+                inf: "optional".to_string(),
+            },
+            descriptions: JaEnString {
+                ja: String::new(),
+                en: String::new(),
+            },
+            data: TypeDef::Raw(DefRaw {
+                t: "raw".to_string(),
+                min_size: 1,
+                max_size: 1,
+            }),
+        };
+        let class = MraClass {
+            eoj: "0x0130".to_string(),
+            valid_release: ValidRelease {
+                from: "A".to_string(),
+                to: "latest".to_string(),
+            },
+            class_name: JaEnString {
+                ja: String::new(),
+                en: String::new(),
+            },
+            short_name: "homeAirConditioner".to_string(),
+            properties: vec![
+                prop("0xA0", "F"),
+                prop("0x80", "latest"),
+                prop("0xA0", "latest"),
+                prop("0xA0", "G"),
+            ],
+        };
+
+        assert_eq!(class.kind(), ClassKind::Device);
+        let props = class.latest_properties();
+        assert_eq!(props.len(), 2);
+        assert_eq!(props[0].epc, "0xA0");
+        assert_eq!(props[0].valid_release.to, "latest");
+        assert_eq!(props[1].epc, "0x80");
+    }
+}
@@ -0,0 +1,109 @@
+//! Resolves `$ref` type definitions against `definitions/definitions.json`.
+
+use super::{DefRef, Definitions, MraClass, TypeDef};
+use crate::error::{ErrorKind, Result};
+
+/// References nested deeper than this are assumed to be cyclic.
+const MAX_DEPTH: usize = 16;
+
+impl Definitions {
+    /// Replaces every `$ref` in `class` with the definition it refers to.
+    pub fn resolve_class(&self, mut class: MraClass) -> Result<MraClass> {
+        for prop in &mut class.properties {
+            prop.data = self.resolve(&prop.data, 0).map_err(|err| {
+                invalid(format!("property {} of {}: {}", prop.epc, class.eoj, err))
+            })?;
+        }
+        Ok(class)
+    }
+
+    /// Returns `def` with all the references resolved.
+    pub fn resolve(&self, def: &TypeDef, depth: usize) -> Result<TypeDef> {
+        if depth > MAX_DEPTH {
+            return Err(invalid("too deeply nested definition".to_string()));
+        }
+        let depth = depth + 1;
+
+        let mut def = match def {
+            TypeDef::Ref(reference) => return self.resolve_ref(reference, depth),
+            def => def.clone(),
+        };
+        match &mut def {
+            TypeDef::OneOf(def) => {
+                for alternative in &mut def.one_of {
+                    *alternative = self.resolve(alternative, depth)?;
+                }
+            }
+            TypeDef::Object(def) => {
+                for element in &mut def.properties {
+                    element.element = self.resolve(&element.element, depth)?;
+                }
+            }
+            TypeDef::BitMap(def) => {
+                for bitmap in &mut def.bitmaps {
+                    bitmap.value = self.resolve(&bitmap.value, depth)?;
+                }
+            }
+            TypeDef::Array(def) => def.items = self.resolve(&def.items, depth)?,
+            _ => {}
+        }
+        Ok(def)
+    }
+
+    fn resolve_ref(&self, reference: &DefRef, depth: usize) -> Result<TypeDef> {
+        let name = reference
+            .def
+            .strip_prefix("#/definitions/")
+            .ok_or_else(|| invalid(format!("unsupported reference {}", reference.def)))?;
+        let def = self
+            .definitions
+            .get(name)
+            .ok_or_else(|| invalid(format!("undefined reference {}", reference.def)))?;
+
+        let mut def = self.resolve(def, depth)?;
+        if let TypeDef::Number(number) = &mut def {
+            number
+                .coefficient
+                .extend(reference.coefficient.iter().cloned());
+            number.overflow_code |= reference.overflow_code;
+            number.underflow_code |= reference.underflow_code;
+        }
+        Ok(def)
+    }
+}
+
+pub(super) fn invalid(message: String) -> crate::Error {
+    ErrorKind::InvalidMra(message).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn resolve_number() {
+        let definitions: Definitions = serde_json::from_str(
+            r##"{ "definitions": {
+                "number_W": { "$ref": "#/definitions/number_int32W" },
+                "number_int32W": { "type": "number", "format": "int32", "unit": "W" }
+            } }"##,
+        )
+        .unwrap();
+        let def: TypeDef = serde_json::from_str(
+            r##"{ "$ref": "#/definitions/number_W", "coefficient": ["0xD3"], "overflowCode": true }"##,
+        )
+        .unwrap();
+
+        let TypeDef::Number(number) = definitions.resolve(&def, 0).unwrap() else {
+            panic!("not a number");
+        };
+        assert_eq!(number.unit.as_deref(), Some("W"));
+        assert_eq!(number.coefficient, ["0xD3"]);
+        assert_eq!(number.overflow(), Some(0x7FFF_FFFF));
+        assert_eq!(number.underflow(), None);
+
+        let undefined: TypeDef =
+            serde_json::from_str(r##"{ "$ref": "#/definitions/x" }"##).unwrap();
+        assert!(definitions.resolve(&undefined, 0).is_err());
+    }
+}
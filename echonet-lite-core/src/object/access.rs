@@ -0,0 +1,56 @@
+use crate::ServiceCode;
+
+/// Whether an access to a property is required by the specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// Must be implemented.
+    Required,
+    /// May be implemented.
+    Optional,
+    /// Must not be accepted.
+    NotApplicable,
+}
+
+impl Access {
+    /// Returns true if the access may be accepted.
+    pub fn is_allowed(self) -> bool {
+        self != Access::NotApplicable
+    }
+}
+
+/// Access rules of a property for Get, Set and announcements (Anno).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AccessRule {
+    pub get: Access,
+    pub set: Access,
+    pub inf: Access,
+}
+
+impl AccessRule {
+    /// Every access is optional. Used for properties whose rules are not known.
+    pub const ANY: Self = Self::new(Access::Optional, Access::Optional, Access::Optional);
+
+    pub const fn new(get: Access, set: Access, inf: Access) -> Self {
+        Self { get, set, inf }
+    }
+
+    /// Returns true if `esv` may be accepted for the property.
+    ///
+    /// InfReq reads the current value and follows the Get rule; `inf` only tells
+    /// whether the property is announced on change (0x9D).
+    /// Service codes other than SetI, SetC, Get and InfReq are always allowed;
+    /// SetGet is checked with SetC for the properties to write and Get for those to read.
+    pub fn allows(&self, esv: ServiceCode) -> bool {
+        match esv {
+            ServiceCode::SetI | ServiceCode::SetC => self.set.is_allowed(),
+            ServiceCode::Get | ServiceCode::InfReq => self.get.is_allowed(),
+            _ => true,
+        }
+    }
+}
+
+impl Default for AccessRule {
+    fn default() -> Self {
+        Self::ANY
+    }
+}
@@ -0,0 +1,382 @@
+use super::{code, ClassDef};
+use crate::error::{Error, ErrorKind};
+use crate::lib::str::FromStr;
+use core::fmt::{self, Formatter};
+use serde::{de::Visitor, ser::SerializeTuple, Deserialize, Serialize};
+
+/// ECHONET Lite class group (X1 of an ECHONET object).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ClassGroup {
+    /// Sensor-related device class group (0x00)
+    Sensor,
+    /// Air conditioner-related device class group (0x01)
+    AirConditioner,
+    /// Housing/facility-related device class group (0x02)
+    Housing,
+    /// Cooking/household-related device class group (0x03)
+    Cooking,
+    /// Health-related device class group (0x04)
+    Health,
+    /// Management/control-related device class group (0x05)
+    Management,
+    /// Audiovisual-related device class group (0x06)
+    Av,
+    /// Profile class group (0x0E)
+    Profile,
+    /// User definition class group (0x0F)
+    UserDefinition,
+    /// Reserved for future use
+    Reserved(u8),
+}
+
+impl ClassGroup {
+    /// Returns whether the class group consists of device objects.
+    pub fn is_device(self) -> bool {
+        u8::from(self) <= 0x06
+    }
+
+    /// English name of the class group.
+    pub fn name(self) -> &'static str {
+        match self {
+            ClassGroup::Sensor => "Sensor-related device",
+            ClassGroup::AirConditioner => "Air conditioner-related device",
+            ClassGroup::Housing => "Housing/facility-related device",
+            ClassGroup::Cooking => "Cooking/household-related device",
+            ClassGroup::Health => "Health-related device",
+            ClassGroup::Management => "Management/control-related device",
+            ClassGroup::Av => "Audiovisual-related device",
+            ClassGroup::Profile => "Profile",
+            ClassGroup::UserDefinition => "User definition",
+            ClassGroup::Reserved(_) => "Reserved",
+        }
+    }
+
+    /// Japanese name of the class group.
+    pub fn name_ja(self) -> &'static str {
+        match self {
+            ClassGroup::Sensor => "センサ関連機器",
+            ClassGroup::AirConditioner => "空調関連機器",
+            ClassGroup::Housing => "住宅・設備関連機器",
+            ClassGroup::Cooking => "調理・家事関連機器",
+            ClassGroup::Health => "健康関連機器",
+            ClassGroup::Management => "管理・操作関連機器",
+            ClassGroup::Av => "AV関連機器",
+            ClassGroup::Profile => "プロファイル",
+            ClassGroup::UserDefinition => "ユーザ定義",
+            ClassGroup::Reserved(_) => "予約",
+        }
+    }
+}
+
+impl From<u8> for ClassGroup {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00 => ClassGroup::Sensor,
+            0x01 => ClassGroup::AirConditioner,
+            0x02 => ClassGroup::Housing,
+            0x03 => ClassGroup::Cooking,
+            0x04 => ClassGroup::Health,
+            0x05 => ClassGroup::Management,
+            0x06 => ClassGroup::Av,
+            0x0E => ClassGroup::Profile,
+            0x0F => ClassGroup::UserDefinition,
+            _ => ClassGroup::Reserved(value),
+        }
+    }
+}
+
+impl From<ClassGroup> for u8 {
+    fn from(value: ClassGroup) -> Self {
+        match value {
+            ClassGroup::Sensor => 0x00,
+            ClassGroup::AirConditioner => 0x01,
+            ClassGroup::Housing => 0x02,
+            ClassGroup::Cooking => 0x03,
+            ClassGroup::Health => 0x04,
+            ClassGroup::Management => 0x05,
+            ClassGroup::Av => 0x06,
+            ClassGroup::Profile => 0x0E,
+            ClassGroup::UserDefinition => 0x0F,
+            ClassGroup::Reserved(raw) => raw,
+        }
+    }
+}
+
+impl fmt::Display for ClassGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// ECHONET Lite class code consisting of class group code (X1) and class code (X2).
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
+pub struct ClassCode(pub(crate) [u8; 2]);
+
+impl ClassCode {
+    /// Creates a class code from class group code and class code.
+    pub const fn new(group: u8, class: u8) -> Self {
+        Self([group, class])
+    }
+
+    /// Class group code (X1).
+    pub fn group_code(self) -> u8 {
+        self.0[0]
+    }
+
+    /// Class code (X2).
+    pub fn class_code(self) -> u8 {
+        self.0[1]
+    }
+
+    /// Class group.
+    pub fn group(self) -> ClassGroup {
+        ClassGroup::from(self.0[0])
+    }
+
+    /// Class group code and class code as bytes.
+    pub fn as_bytes(self) -> [u8; 2] {
+        self.0
+    }
+
+    /// Returns whether the class is the node profile class.
+    pub fn is_node_profile(self) -> bool {
+        self.0 == code::PROFILE
+    }
+
+    /// Returns whether the class is a device class.
+    pub fn is_device(self) -> bool {
+        self.group().is_device()
+    }
+
+    /// English name of the class if the class is built-in.
+    pub fn name(self) -> Option<&'static str> {
+        ClassDef::builtin(self.0).map(|class| class.name.en)
+    }
+
+    /// Japanese name of the class if the class is built-in.
+    pub fn name_ja(self) -> Option<&'static str> {
+        ClassDef::builtin(self.0).map(|class| class.name.ja)
+    }
+}
+
+impl From<[u8; 2]> for ClassCode {
+    fn from(value: [u8; 2]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ClassCode> for [u8; 2] {
+    fn from(value: ClassCode) -> Self {
+        value.0
+    }
+}
+
+impl From<ElClass> for ClassCode {
+    fn from(value: ElClass) -> Self {
+        Self(value.into())
+    }
+}
+
+impl fmt::Display for ClassCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02X} {:02X}", self.0[0], self.0[1])
+    }
+}
+
+/// Parses hex notation of a class code such as `0x0130` or `0130`.
+impl FromStr for ClassCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [group, class] = parse_hex(s)?;
+        Ok(Self([group, class]))
+    }
+}
+
+/// Parses `N` bytes of hex digits with an optional `0x` prefix.
+pub(crate) fn parse_hex<const N: usize>(s: &str) -> Result<[u8; N], Error> {
+    let digits = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    if digits.len() != N * 2 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ErrorKind::InvalidNotation.into());
+    }
+    let mut bytes = [0u8; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16)
+            .map_err(|_| Error::from(ErrorKind::InvalidNotation))?;
+    }
+    Ok(bytes)
+}
+
+/// echonet-lite class representation.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum ElClass {
+    HomeAC,
+    Hp,
+    InstantaneousWaterHeater,
+    Pv,
+    FuelCell,
+    Battery,
+    Evps,
+    Metering,
+    SmartMeter,
+    MultiInputPCS,
+    GeneralLighting,
+    MonoFunctionLighting,
+    LightingSystem,
+    Controller,
+    Profile,
+    Unknown([u8; 2]),
+}
+
+impl Serialize for ElClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let raw = Into::<[u8; 2]>::into(*self);
+        let mut seq = serializer.serialize_tuple(2)?;
+        seq.serialize_element(&raw[0])?;
+        seq.serialize_element(&raw[1])?;
+        seq.end()
+    }
+}
+
+struct ElClassVisitor;
+impl<'de> Visitor<'de> for ElClassVisitor {
+    type Value = (u8, u8);
+
+    fn expecting(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter.write_str("never failed")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let group: u8 = seq.next_element()?.unwrap();
+        let class: u8 = seq.next_element()?.unwrap();
+        Ok((group, class))
+    }
+}
+
+impl<'de> Deserialize<'de> for ElClass {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (group, class) = deserializer.deserialize_tuple(2, ElClassVisitor)?;
+        Ok(ElClass::from(&[group, class]))
+    }
+}
+
+impl From<&[u8; 2]> for ElClass {
+    fn from(value: &[u8; 2]) -> Self {
+        use code::*;
+        use ElClass::*;
+        match *value {
+            HOME_AIR_CONDITIONER => HomeAC,
+            HP => Hp,
+            INSTANTANEOUS_WATER_HEATER => InstantaneousWaterHeater,
+            HOUSEHOLD_SOLAR_POWER => Pv,
+            FUEL_CELL => FuelCell,
+            STORAGE_BATTERY => Battery,
+            EVPS => Evps,
+            POWER_DISTRIBUTION_BOARD_METERING => Metering,
+            SMART_METER => SmartMeter,
+            MULTI_INPUT_PCS => MultiInputPCS,
+            GENERAL_LIGHTING => GeneralLighting,
+            MONO_FUNCTION_LIGHTING => MonoFunctionLighting,
+            LIGHTING_SYSTEM => LightingSystem,
+            CONTROLLER => Controller,
+            PROFILE => Profile,
+            _ => Unknown(*value),
+        }
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<[u8; 2]> for ElClass {
+    fn into(self) -> [u8; 2] {
+        use code::*;
+        use ElClass::*;
+        match self {
+            HomeAC => HOME_AIR_CONDITIONER,
+            Hp => HP,
+            InstantaneousWaterHeater => INSTANTANEOUS_WATER_HEATER,
+            Pv => HOUSEHOLD_SOLAR_POWER,
+            FuelCell => FUEL_CELL,
+            Battery => STORAGE_BATTERY,
+            Evps => EVPS,
+            Metering => POWER_DISTRIBUTION_BOARD_METERING,
+            SmartMeter => SMART_METER,
+            MultiInputPCS => MULTI_INPUT_PCS,
+            GeneralLighting => GENERAL_LIGHTING,
+            MonoFunctionLighting => MONO_FUNCTION_LIGHTING,
+            LightingSystem => LIGHTING_SYSTEM,
+            Controller => CONTROLLER,
+            Profile => PROFILE,
+            Unknown(raw) => raw,
+        }
+    }
+}
+
+impl From<ClassCode> for ElClass {
+    fn from(value: ClassCode) -> Self {
+        ElClass::from(&value.0)
+    }
+}
+
+impl ElClass {
+    /// English name of the class if the class is built-in.
+    pub fn name(self) -> Option<&'static str> {
+        ClassCode::from(self).name()
+    }
+
+    /// Japanese name of the class if the class is built-in.
+    pub fn name_ja(self) -> Option<&'static str> {
+        ClassCode::from(self).name_ja()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn class_code() {
+        let class = ClassCode::new(0x02, 0x88);
+        assert_eq!(class.group(), ClassGroup::Housing);
+        assert_eq!(class.class_code(), 0x88);
+        assert!(class.is_device());
+        assert!(!class.is_node_profile());
+        assert_eq!(class.name(), Some("Smart Meter"));
+        assert_eq!(class.name_ja(), Some("低圧スマート電力量メータ"));
+        assert_eq!(ElClass::from(class), ElClass::SmartMeter);
+        assert_eq!(ClassCode::from(ElClass::SmartMeter), class);
+
+        let profile = ClassCode::from(code::PROFILE);
+        assert_eq!(profile.group(), ClassGroup::Profile);
+        assert!(!profile.is_device());
+        assert!(profile.is_node_profile());
+    }
+
+    #[test]
+    fn parse_class_code() {
+        assert_eq!(
+            "0x0130".parse::<ClassCode>().unwrap(),
+            ClassCode::new(0x01, 0x30)
+        );
+        assert_eq!(
+            "05FF".parse::<ClassCode>().unwrap(),
+            ClassCode::new(0x05, 0xFF)
+        );
+        assert!("0x013".parse::<ClassCode>().is_err());
+        assert!("0x01G0".parse::<ClassCode>().is_err());
+        assert!("0x+130".parse::<ClassCode>().is_err());
+    }
+}
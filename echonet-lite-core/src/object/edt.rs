@@ -0,0 +1,121 @@
+use super::EchonetObject;
+use crate::lib::Vec;
+
+/// Decodes a property map (0x9D, 0x9E and 0x9F) into the EPCs in ascending order.
+///
+/// The EDT consists of the number of properties followed by their EPCs.
+/// From 16 properties on, the EPCs are a 16-byte bitmap instead,
+/// where bit `j` of byte `i` stands for EPC `0x80 + 0x10 * j + i`.
+///
+/// # Examples
+///
+/// ```
+/// use echonet_lite::object::{decode_property_map, encode_property_map};
+///
+/// assert_eq!(decode_property_map(&[0x02, 0x80, 0x81]).unwrap(), [0x80, 0x81]);
+///
+/// let epcs: Vec<u8> = (0x80..0x90).collect();
+/// let edt = encode_property_map(&epcs);
+/// assert_eq!(edt.len(), 17);
+/// assert_eq!(decode_property_map(&edt).unwrap(), epcs);
+/// ```
+pub fn decode_property_map(edt: &[u8]) -> Option<Vec<u8>> {
+    let (&num, rest) = edt.split_first()?;
+    let mut epcs = if num < 16 {
+        if rest.len() != num as usize {
+            return None;
+        }
+        rest.to_vec()
+    } else {
+        let bitmap: &[u8; 16] = rest.try_into().ok()?;
+        (0x80..=0xFFu8)
+            .filter(|epc| bitmap[(epc & 0x0F) as usize] & (1 << ((epc >> 4) - 8)) != 0)
+            .collect()
+    };
+    epcs.sort_unstable();
+    Some(epcs)
+}
+
+/// Encodes EPCs into a property map, see [`decode_property_map`].
+pub fn encode_property_map(epcs: &[u8]) -> Vec<u8> {
+    let mut epcs = epcs.to_vec();
+    epcs.sort_unstable();
+    epcs.dedup();
+
+    let mut edt = Vec::with_capacity(17);
+    edt.push(epcs.len() as u8);
+    if epcs.len() < 16 {
+        edt.extend_from_slice(&epcs);
+    } else {
+        let mut bitmap = [0u8; 16];
+        for epc in epcs.into_iter().filter(|epc| *epc >= 0x80) {
+            bitmap[(epc & 0x0F) as usize] |= 1 << ((epc >> 4) - 8);
+        }
+        edt.extend_from_slice(&bitmap);
+    }
+    edt
+}
+
+/// Decodes a list of objects, e.g. the instance lists 0xD5 and 0xD6 of the node profile.
+///
+/// The EDT consists of the number of objects followed by their EOJs.
+pub fn decode_object_list(edt: &[u8]) -> Option<Vec<EchonetObject>> {
+    let (num, eojs) = edt.split_first()?;
+    if eojs.len() != *num as usize * 3 {
+        return None;
+    }
+    eojs.chunks_exact(3)
+        .map(|eoj| EchonetObject::try_from(eoj).ok())
+        .collect()
+}
+
+/// Encodes a list of objects, see [`decode_object_list`].
+pub fn encode_object_list(eojs: &[EchonetObject]) -> Vec<u8> {
+    let mut edt = Vec::with_capacity(1 + eojs.len() * 3);
+    edt.push(eojs.len() as u8);
+    for eoj in eojs {
+        edt.extend_from_slice(&<[u8; 3]>::from(*eoj));
+    }
+    edt
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn property_map_bitmap() {
+        let epcs = [
+            0x80, 0x81, 0x82, 0x83, 0x88, 0x8A, 0x9D, 0x9E, 0x9F, 0xD3, 0xD4, 0xD5,
+        ];
+        let mut all = epcs.to_vec();
+        all.extend([0xD6, 0xD7, 0xE0, 0xFF]);
+        let edt = encode_property_map(&all);
+        assert_eq!(edt[0], 16);
+        // 0x80, 0xD0 (none), 0xE0 and 0xF0 (none) are in byte 0
+        assert_eq!(edt[1], 0b0100_0001);
+        assert_eq!(decode_property_map(&edt).unwrap(), all);
+
+        assert_eq!(decode_property_map(&[0x03, 0x80]), None);
+        assert_eq!(decode_property_map(&[0x10, 0x00]), None);
+        assert_eq!(
+            decode_property_map(&encode_property_map(&epcs)).unwrap(),
+            epcs
+        );
+    }
+
+    #[test]
+    fn object_list() {
+        let edt = [0x02, 0x01, 0x30, 0x01, 0x02, 0x88, 0x01];
+        let list = decode_object_list(&edt).unwrap();
+        assert_eq!(encode_object_list(&list), edt);
+        assert_eq!(
+            list,
+            [
+                EchonetObject::from([0x01, 0x30, 0x01]),
+                EchonetObject::from([0x02, 0x88, 0x01])
+            ]
+        );
+        assert_eq!(decode_object_list(&[0x02, 0x01, 0x30, 0x01]), None);
+    }
+}
@@ -0,0 +1,62 @@
+use super::ClassPacket;
+use core::fmt;
+
+/// Language used for class and property names in displays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// Japanese
+    #[default]
+    Ja,
+    /// English
+    En,
+}
+
+/// A name both in Japanese and English.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalizedName {
+    pub ja: &'static str,
+    pub en: &'static str,
+}
+
+impl LocalizedName {
+    pub const fn new(ja: &'static str, en: &'static str) -> Self {
+        Self { ja, en }
+    }
+
+    /// Gets the name in `locale`.
+    pub fn get(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Ja => self.ja,
+            Locale::En => self.en,
+        }
+    }
+}
+
+/// Displays a value with names in the given locale.
+///
+/// # Examples
+///
+/// ```
+/// use echonet_lite::object::{ClassPacket, Locale};
+/// use echonet_lite::{prelude::*, props};
+///
+/// let packet = ClassPacket::new([0x02, 0x88, 0x01].into(), props!([0xE7, [0x00, 0x00, 0x01, 0xF4]]));
+/// let display = packet.localized(Locale::En).to_string();
+/// assert!(display.contains("[Measured instantaneous electric power]"));
+/// ```
+pub struct Localized<'a, T> {
+    pub(super) value: &'a T,
+    pub(super) locale: Locale,
+}
+
+impl<'a, T> Localized<'a, T> {
+    pub fn new(value: &'a T, locale: Locale) -> Self {
+        Self { value, locale }
+    }
+}
+
+impl fmt::Display for Localized<'_, ClassPacket> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt_localized(f, self.locale)
+    }
+}
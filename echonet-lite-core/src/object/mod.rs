@@ -1,151 +1,273 @@
-use crate::{deserialize, ElPacket, Properties};
-use core::fmt::{self, Formatter};
+use crate::error::{Error, ErrorKind};
+use crate::lib::str::FromStr;
+use crate::lib::Vec;
+use crate::{deserialize, ElPacket, Properties, Property};
+pub use access::*;
+pub use class::*;
+use core::fmt;
+pub use edt::*;
+pub use locale::*;
+pub use property::*;
 pub use property_maps::*;
-use serde::{de::Visitor, ser::SerializeTuple, Deserialize, Serialize};
-
+pub use registry::*;
+pub use release::*;
+use serde::{Deserialize, Serialize};
+
+mod access;
+mod class;
+mod edt;
+mod locale;
+mod property;
 mod property_maps;
+mod registry;
+mod release;
+
+/// Declares the built-in classes.
+///
+/// Each entry generates a packet type, a [`ClassPacket`] variant and a [`ClassDef`]
+/// registered in [`BUILTIN_CLASSES`].
+macro_rules! builtin_classes {
+    ( $(
+        $(#[$doc:meta])*
+        $variant:ident($ty:ident) {
+            code: $code:expr,
+            properties: $map:ident,
+            releases: $releases:ident,
+            name: $name:expr,
+            name_ja: $name_ja:expr,
+            short_name: $short_name:expr $(,)?
+        }
+    )* ) => {
+        /// Packet specified to an ECHONET class.
+        pub enum ClassPacket {
+            /// Any unimplemented class fallback
+            Unimplemented(UnimplementedPacket),
+            /// Class registered outside of this crate
+            Custom(CustomPacket),
+            $(
+                $(#[$doc])*
+                $variant($ty),
+            )*
+        }
+
+        impl ClassPacket {
+            /// fetches the properties for this ClassPacket, when appropriate.
+            pub fn properties(&self) -> &Properties {
+                match self {
+                    Self::Unimplemented(p) => p.properties(),
+                    Self::Custom(p) => p.properties(),
+                    $( Self::$variant(p) => p.properties(), )*
+                }
+            }
+        }
 
-/// Packet specified to an ECHONET class.
-pub enum ClassPacket {
-    /// Any unimplemented class fallback
-    Unimplemented(UnimplementedPacket),
+        impl ClassPacket {
+            fn fmt_localized(&self, f: &mut fmt::Formatter<'_>, locale: Locale) -> fmt::Result {
+                match self {
+                    ClassPacket::Unimplemented(v) => v.fmt_localized(f, locale),
+                    ClassPacket::Custom(v) => v.fmt_localized(f, locale),
+                    $( ClassPacket::$variant(v) => v.fmt_localized(f, locale), )*
+                }
+            }
+        }
+
+        $(
+            pub struct $ty(Properties);
+            convert_packet!($code, $ty, $map, LocalizedName::new($name_ja, $name));
+        )*
+
+        /// Classes implemented by this crate.
+        pub static BUILTIN_CLASSES: &[ClassDef] = &[
+            $(
+                ClassDef {
+                    code: $code,
+                    name: LocalizedName::new($name_ja, $name),
+                    short_name: $short_name,
+                    properties: PropertyMap::Static(&$map),
+                    releases: $releases,
+                    decode: |_, props| ClassPacket::$variant($ty(props)),
+                },
+            )*
+        ];
+    };
+}
+
+builtin_classes! {
     /// House hold solar power class packet
-    SolarPower(SolarPowerPacket),
+    SolarPower(SolarPowerPacket) {
+        code: code::HOUSEHOLD_SOLAR_POWER,
+        properties: HOUSEHOLD_SOLAR_POWER_CLASS,
+        releases: HOUSEHOLD_SOLAR_POWER_RELEASES,
+        name: "House Hold Solar Power",
+        name_ja: "住宅用太陽光発電",
+        short_name: "Household solar power",
+    }
     /// Storage battery class packet
-    StorageBattery(StorageBatteryPacket),
+    StorageBattery(StorageBatteryPacket) {
+        code: code::STORAGE_BATTERY,
+        properties: STORAGE_BATTERY_CLASS,
+        releases: STORAGE_BATTERY_RELEASES,
+        name: "Storage Battery",
+        name_ja: "蓄電池",
+        short_name: "Storage battery",
+    }
     /// Electric vehicle charger/discharger class packet
-    Evps(EvpsPacket),
+    Evps(EvpsPacket) {
+        code: code::EVPS,
+        properties: EVPS_CLASS,
+        releases: EVPS_RELEASES,
+        name: "EVPS",
+        name_ja: "電気自動車充放電器",
+        short_name: "V2H",
+    }
     /// Heat pump
-    Hp(HpPacket),
+    Hp(HpPacket) {
+        code: code::HP,
+        properties: HP_CLASS,
+        releases: HP_RELEASES,
+        name: "HP",
+        name_ja: "電気温水器",
+        short_name: "Heat pump",
+    }
     /// Smart Meter class packet
-    SmartMeter(SmartMeterPacket),
+    SmartMeter(SmartMeterPacket) {
+        code: code::SMART_METER,
+        properties: SMART_METER_CLASS,
+        releases: SMART_METER_RELEASES,
+        name: "Smart Meter",
+        name_ja: "低圧スマート電力量メータ",
+        short_name: "Smart meter",
+    }
     /// Home Air Conditioner class packet
-    AirConditioner(AirConditionerPacket),
+    AirConditioner(AirConditionerPacket) {
+        code: code::HOME_AIR_CONDITIONER,
+        properties: HOME_AIR_CONDITIONER_CLASS,
+        releases: HOME_AIR_CONDITIONER_RELEASES,
+        name: "Home Air Conditioner",
+        name_ja: "家庭用エアコン",
+        short_name: "Home AC",
+    }
     /// Power Distribution Board Metering class packet
-    Metering(MeteringPacket),
+    Metering(MeteringPacket) {
+        code: code::POWER_DISTRIBUTION_BOARD_METERING,
+        properties: POWER_DISTRIBUTION_BOARD_METERING_CLASS,
+        releases: POWER_DISTRIBUTION_BOARD_METERING_RELEASES,
+        name: "Power Distribution Board Metering",
+        name_ja: "分電盤メータリング",
+        short_name: "Power distribution Metering",
+    }
     /// Fuel Cell class packet
-    FuelCell(FuelCellPacket),
+    FuelCell(FuelCellPacket) {
+        code: code::FUEL_CELL,
+        properties: FUEL_CELL_CLASS,
+        releases: FUEL_CELL_RELEASES,
+        name: "Fuel Cell",
+        name_ja: "燃料電池",
+        short_name: "Fuel cell",
+    }
     /// Instantaneous Water Heater class packet
-    InstantaneousWaterHeater(InstantaneousWaterHeaterPacket),
+    InstantaneousWaterHeater(InstantaneousWaterHeaterPacket) {
+        code: code::INSTANTANEOUS_WATER_HEATER,
+        properties: INSTANTANEOUS_WATER_HEATER_CLASS,
+        releases: INSTANTANEOUS_WATER_HEATER_RELEASES,
+        name: "Instantaneous Water Heater",
+        name_ja: "瞬間式給湯機",
+        short_name: "Instantaneous water heater",
+    }
     /// General Lighting class packet
-    GeneralLighting(GeneralLightingPacket),
+    GeneralLighting(GeneralLightingPacket) {
+        code: code::GENERAL_LIGHTING,
+        properties: GENERAL_LIGHTING_CLASS,
+        releases: GENERAL_LIGHTING_RELEASES,
+        name: "General Lighting",
+        name_ja: "一般照明",
+        short_name: "General lighting",
+    }
     /// Mono Function Lighting class packet
-    MonoFunctionLighting(MonoFunctionLightingPacket),
+    MonoFunctionLighting(MonoFunctionLightingPacket) {
+        code: code::MONO_FUNCTION_LIGHTING,
+        properties: MONO_FUNCTION_LIGHTING_CLASS,
+        releases: MONO_FUNCTION_LIGHTING_RELEASES,
+        name: "Mono Function Lighting",
+        name_ja: "単機能照明",
+        short_name: "Mono function lighting",
+    }
     /// Lighting System class packet
-    LightingSystem(LightingSystemPacket),
+    LightingSystem(LightingSystemPacket) {
+        code: code::LIGHTING_SYSTEM,
+        properties: LIGHTING_SYSTEM_CLASS,
+        releases: LIGHTING_SYSTEM_RELEASES,
+        name: "Lighting System",
+        name_ja: "照明システム",
+        short_name: "Lighting system",
+    }
+    /// Multi Input PCS class packet
+    MultiInputPcs(MultiInputPcsPacket) {
+        code: code::MULTI_INPUT_PCS,
+        properties: MULTI_INPUT_PCS_CLASS,
+        releases: MULTI_INPUT_PCS_RELEASES,
+        name: "Multi Input PCS",
+        name_ja: "マルチ入力PCS",
+        short_name: "Multi input PCS",
+    }
     /// Node profile class packet
-    Profile(ProfilePacket),
+    Profile(ProfilePacket) {
+        code: code::PROFILE,
+        properties: PROFILE_CLASS,
+        releases: PROFILE_RELEASES,
+        name: "Node Profile",
+        name_ja: "ノードプロファイル",
+        short_name: "Profile",
+    }
     /// Controller class
-    Controller(ControllerPacket),
+    Controller(ControllerPacket) {
+        code: code::CONTROLLER,
+        properties: CONTROLLER_CLASS,
+        releases: CONTROLLER_RELEASES,
+        name: "Controller",
+        name_ja: "コントローラ",
+        short_name: "Controller",
+    }
 }
 
 impl ClassPacket {
+    /// Creates a packet of the built-in class of `eoj`.
+    ///
+    /// Use [`ClassRegistry::decode`] to take user defined classes into account.
     pub fn new(eoj: EchonetObject, props: Properties) -> ClassPacket {
-        match eoj.class {
-            ClassCode(code::HOUSEHOLD_SOLAR_POWER) => {
-                ClassPacket::SolarPower(SolarPowerPacket(props))
-            }
-            ClassCode(code::STORAGE_BATTERY) => {
-                ClassPacket::StorageBattery(StorageBatteryPacket(props))
-            }
-            ClassCode(code::EVPS) => ClassPacket::Evps(EvpsPacket(props)),
-            ClassCode(code::HP) => ClassPacket::Hp(HpPacket(props)),
-            ClassCode(code::SMART_METER) => ClassPacket::SmartMeter(SmartMeterPacket(props)),
-            ClassCode(code::HOME_AIR_CONDITIONER) => {
-                ClassPacket::AirConditioner(AirConditionerPacket(props))
-            }
-            ClassCode(code::POWER_DISTRIBUTION_BOARD_METERING) => {
-                ClassPacket::Metering(MeteringPacket(props))
-            }
-            ClassCode(code::FUEL_CELL) => ClassPacket::FuelCell(FuelCellPacket(props)),
-            ClassCode(code::INSTANTANEOUS_WATER_HEATER) => {
-                ClassPacket::InstantaneousWaterHeater(InstantaneousWaterHeaterPacket(props))
-            }
-            ClassCode(code::GENERAL_LIGHTING) => {
-                ClassPacket::GeneralLighting(GeneralLightingPacket(props))
-            }
-            ClassCode(code::MONO_FUNCTION_LIGHTING) => {
-                ClassPacket::MonoFunctionLighting(MonoFunctionLightingPacket(props))
-            }
-            ClassCode(code::LIGHTING_SYSTEM) => {
-                ClassPacket::LightingSystem(LightingSystemPacket(props))
-            }
-            ClassCode(code::PROFILE) => ClassPacket::Profile(ProfilePacket(props)),
-            ClassCode(code::CONTROLLER) => ClassPacket::Controller(ControllerPacket(props)),
-            _ => ClassPacket::Unimplemented(UnimplementedPacket(eoj.class, props)),
+        match ClassDef::builtin(eoj.class.0) {
+            Some(class) => (class.decode)(class, props),
+            None => ClassPacket::Unimplemented(UnimplementedPacket(eoj.class, props)),
         }
     }
 
-    /// fetches the properties for this ClassPacket, when appropriate.
-    pub fn properties(&self) -> &Properties {
-        match self {
-            Self::Unimplemented(p) => p.properties(),
-            Self::SolarPower(p) => p.properties(),
-            Self::StorageBattery(p) => p.properties(),
-            Self::Evps(p) => p.properties(),
-            Self::Hp(p) => p.properties(),
-            Self::SmartMeter(p) => p.properties(),
-            Self::AirConditioner(p) => p.properties(),
-            Self::Metering(p) => p.properties(),
-            Self::FuelCell(p) => p.properties(),
-            Self::InstantaneousWaterHeater(p) => p.properties(),
-            Self::GeneralLighting(p) => p.properties(),
-            Self::MonoFunctionLighting(p) => p.properties(),
-            Self::LightingSystem(p) => p.properties(),
-            Self::Profile(p) => p.properties(),
-            Self::Controller(p) => p.properties(),
-        }
+    /// Displays the packet with names in `locale`.
+    ///
+    /// [`Display`](fmt::Display) of `ClassPacket` uses the default locale.
+    pub fn localized(&self, locale: Locale) -> Localized<'_, ClassPacket> {
+        Localized::new(self, locale)
     }
-}
 
-impl From<ElPacket> for ClassPacket {
-    fn from(value: ElPacket) -> Self {
-        match value.seoj.class {
-            ClassCode(code::HOUSEHOLD_SOLAR_POWER) => ClassPacket::SolarPower(value.into()),
-            ClassCode(code::STORAGE_BATTERY) => ClassPacket::StorageBattery(value.into()),
-            ClassCode(code::EVPS) => ClassPacket::Evps(value.into()),
-            ClassCode(code::HP) => ClassPacket::Hp(value.into()),
-            ClassCode(code::SMART_METER) => ClassPacket::SmartMeter(value.into()),
-            ClassCode(code::HOME_AIR_CONDITIONER) => ClassPacket::AirConditioner(value.into()),
-            ClassCode(code::POWER_DISTRIBUTION_BOARD_METERING) => {
-                ClassPacket::Metering(value.into())
-            }
-            ClassCode(code::FUEL_CELL) => ClassPacket::FuelCell(value.into()),
-            ClassCode(code::INSTANTANEOUS_WATER_HEATER) => {
-                ClassPacket::InstantaneousWaterHeater(value.into())
-            }
-            ClassCode(code::GENERAL_LIGHTING) => ClassPacket::GeneralLighting(value.into()),
-            ClassCode(code::MONO_FUNCTION_LIGHTING) => {
-                ClassPacket::MonoFunctionLighting(value.into())
-            }
-            ClassCode(code::LIGHTING_SYSTEM) => ClassPacket::LightingSystem(value.into()),
-            ClassCode(code::PROFILE) => ClassPacket::Profile(value.into()),
-            ClassCode(code::CONTROLLER) => ClassPacket::Controller(value.into()),
-            _ => ClassPacket::Unimplemented(value.into()),
-        }
+    /// Creates a [`CustomPacket`].
+    ///
+    /// This is intended to be used as [`ClassDef::decode`] of user defined classes.
+    pub fn custom(class: &'static ClassDef, props: Properties) -> ClassPacket {
+        ClassPacket::Custom(CustomPacket { class, props })
     }
 }
 
 impl fmt::Display for ClassPacket {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            ClassPacket::SolarPower(v) => write!(f, "{v}")?,
-            ClassPacket::StorageBattery(v) => write!(f, "{v}")?,
-            ClassPacket::Evps(v) => write!(f, "{v}")?,
-            ClassPacket::Hp(v) => write!(f, "{v}")?,
-            ClassPacket::SmartMeter(v) => write!(f, "{v}")?,
-            ClassPacket::AirConditioner(v) => write!(f, "{v}")?,
-            ClassPacket::Metering(v) => write!(f, "{v}")?,
-            ClassPacket::FuelCell(v) => write!(f, "{v}")?,
-            ClassPacket::InstantaneousWaterHeater(v) => write!(f, "{v}")?,
-            ClassPacket::GeneralLighting(v) => write!(f, "{v}")?,
-            ClassPacket::MonoFunctionLighting(v) => write!(f, "{v}")?,
-            ClassPacket::LightingSystem(v) => write!(f, "{v}")?,
-            ClassPacket::Profile(v) => write!(f, "{v}")?,
-            ClassPacket::Controller(v) => write!(f, "{v}")?,
-            ClassPacket::Unimplemented(v) => write!(f, "{v}")?,
-        }
-        Ok(())
+        self.fmt_localized(f, Locale::default())
+    }
+}
+
+/// Interprets the packet by the class of the device side object.
+///
+/// See [`ElPacket::device_object`].
+impl From<ElPacket> for ClassPacket {
+    fn from(value: ElPacket) -> Self {
+        ClassPacket::new(value.device_object(), value.props)
     }
 }
 
@@ -162,25 +284,11 @@ pub mod code {
     pub const GENERAL_LIGHTING: [u8; 2] = [0x02, 0x90];
     pub const MONO_FUNCTION_LIGHTING: [u8; 2] = [0x02, 0x91];
     pub const LIGHTING_SYSTEM: [u8; 2] = [0x02, 0xA3];
+    pub const MULTI_INPUT_PCS: [u8; 2] = [0x02, 0xA5];
     pub const CONTROLLER: [u8; 2] = [0x05, 0xFF];
     pub const PROFILE: [u8; 2] = [0x0E, 0xF0];
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
-struct ClassCode([u8; 2]);
-
-impl From<[u8; 2]> for ClassCode {
-    fn from(value: [u8; 2]) -> Self {
-        Self(value)
-    }
-}
-
-impl fmt::Display for ClassCode {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:02X} {:02X}", self.0[0], self.0[1])
-    }
-}
-
 pub struct UnimplementedPacket(ClassCode, Properties);
 
 impl UnimplementedPacket {
@@ -189,12 +297,12 @@ impl UnimplementedPacket {
     }
 }
 
-impl fmt::Display for UnimplementedPacket {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl UnimplementedPacket {
+    fn fmt_localized(&self, f: &mut fmt::Formatter, locale: Locale) -> fmt::Result {
         writeln!(f, "Unimplemented Class: {}", self.0)?;
         for prop in self.1.iter() {
-            if let Some(name) = SUPER_CLASS.get(&prop.epc) {
-                writeln!(f, "{prop}\t\t[{name}]")?;
+            if let Some(def) = SUPER_CLASS.get(&prop.epc) {
+                fmt_property(f, locale, prop, def)?;
                 continue;
             }
             writeln!(f, "{prop}\t\t[unknown]")?;
@@ -203,12 +311,88 @@ impl fmt::Display for UnimplementedPacket {
     }
 }
 
+impl fmt::Display for UnimplementedPacket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, Locale::default())
+    }
+}
+
 impl From<ElPacket> for UnimplementedPacket {
     fn from(value: ElPacket) -> Self {
-        UnimplementedPacket(value.seoj.class, value.props)
+        UnimplementedPacket(value.device_object().class, value.props)
+    }
+}
+
+/// Packet of a class registered outside of this crate.
+pub struct CustomPacket {
+    class: &'static ClassDef,
+    props: Properties,
+}
+
+impl CustomPacket {
+    /// Gets the class definition of this packet.
+    pub fn class(&self) -> &'static ClassDef {
+        self.class
+    }
+
+    /// Gets the properties used to construct this ClassPacket
+    pub fn properties(&self) -> &Properties {
+        &self.props
     }
 }
 
+impl CustomPacket {
+    fn fmt_localized(&self, f: &mut fmt::Formatter, locale: Locale) -> fmt::Result {
+        fmt_class(
+            f,
+            locale,
+            self.class.name,
+            self.class.code,
+            &self.class.properties,
+            &self.props,
+        )
+    }
+}
+
+impl fmt::Display for CustomPacket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, Locale::default())
+    }
+}
+
+fn fmt_class(
+    f: &mut fmt::Formatter,
+    locale: Locale,
+    class_desc: LocalizedName,
+    code: [u8; 2],
+    class: &PropertyMap,
+    props: &Properties,
+) -> fmt::Result {
+    let class_desc = class_desc.get(locale);
+    writeln!(f, "{}: 0x{:02X}{:02X}", class_desc, code[0], code[1])?;
+    for prop in props.iter() {
+        if let Some(def) = SUPER_CLASS.get(&prop.epc).or_else(|| class.get(prop.epc)) {
+            fmt_property(f, locale, prop, def)?;
+            continue;
+        }
+        writeln!(f, "{prop}\t\t[unknown]")?;
+    }
+    Ok(())
+}
+
+fn fmt_property(
+    f: &mut fmt::Formatter,
+    locale: Locale,
+    prop: &Property,
+    def: &PropertyDef,
+) -> fmt::Result {
+    write!(f, "{prop}\t\t[{}]", def.name.get(locale))?;
+    if let Some(value) = def.decode(&prop.edt) {
+        write!(f, " {}", Localized::new(&value, locale))?;
+    }
+    writeln!(f)
+}
+
 macro_rules! convert_packet {
     ( $code:expr, $ty:ty, $class:expr, $class_desc:expr) => {
         impl $ty {
@@ -221,136 +405,77 @@ macro_rules! convert_packet {
             }
         }
 
+        impl $ty {
+            fn fmt_localized(&self, f: &mut fmt::Formatter, locale: Locale) -> fmt::Result {
+                let class = PropertyMap::Static(&$class);
+                fmt_class(f, locale, $class_desc, Self::CODE, &class, &self.0)
+            }
+        }
+
         impl fmt::Display for $ty {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                writeln!(
-                    f,
-                    "{}: 0x{:02X}{:02X}",
-                    $class_desc,
-                    Self::CODE[0],
-                    Self::CODE[1]
-                )?;
-                for prop in self.0.iter() {
-                    if let Some(name) = SUPER_CLASS.get(&prop.epc) {
-                        writeln!(f, "{prop}\t\t[{name}]")?;
-                        continue;
-                    }
-                    if let Some(name) = $class.get(&prop.epc) {
-                        writeln!(f, "{prop}\t\t[{name}]")?;
-                        continue;
-                    }
-                    writeln!(f, "{prop}\t\t[unknown]")?;
-                }
-                Ok(())
+                self.fmt_localized(f, Locale::default())
             }
         }
 
-        impl From<ElPacket> for $ty {
-            fn from(value: ElPacket) -> Self {
-                if value.seoj.class != ClassCode(Self::CODE) {
-                    panic!("invalid source object class.")
+        impl TryFrom<ElPacket> for $ty {
+            type Error = crate::error::Error;
+
+            fn try_from(value: ElPacket) -> Result<Self, Self::Error> {
+                let found = value.device_object().class.0;
+                if found != Self::CODE {
+                    return Err(ErrorKind::ClassMismatch {
+                        expected: Self::CODE,
+                        found,
+                    }
+                    .into());
                 }
-                Self(value.props)
+                Ok(Self(value.props))
             }
         }
     };
 }
+use convert_packet;
+
+impl MultiInputPcsPacket {
+    const CUMULATIVE_ENERGY_NORMAL: u8 = 0xE0;
+    const CUMULATIVE_ENERGY_REVERSE: u8 = 0xE3;
+    const CONNECTED_DEVICES: u8 = 0xE8;
+
+    fn edt(&self, epc: u8) -> Option<&[u8]> {
+        self.0
+            .iter()
+            .find(|prop| prop.epc == epc)
+            .map(|prop| &*prop.edt)
+    }
 
-pub struct SmartMeterPacket(Properties);
-convert_packet!(
-    code::SMART_METER,
-    SmartMeterPacket,
-    SMART_METER_CLASS,
-    "Smart Meter"
-);
-
-pub struct SolarPowerPacket(Properties);
-convert_packet!(
-    code::HOUSEHOLD_SOLAR_POWER,
-    SolarPowerPacket,
-    HOUSEHOLD_SOLAR_POWER_CLASS,
-    "House Hold Solar Power"
-);
-pub struct StorageBatteryPacket(Properties);
-convert_packet!(
-    code::STORAGE_BATTERY,
-    StorageBatteryPacket,
-    STORAGE_BATTERY_CLASS,
-    "Storage Battery"
-);
-
-pub struct EvpsPacket(Properties);
-convert_packet!(code::EVPS, EvpsPacket, EVPS_CLASS, "EVPS");
-
-pub struct HpPacket(Properties);
-convert_packet!(code::HP, HpPacket, HP_CLASS, "HP");
-
-pub struct AirConditionerPacket(Properties);
-convert_packet!(
-    code::HOME_AIR_CONDITIONER,
-    AirConditionerPacket,
-    HOME_AIR_CONDITIONER_CLASS,
-    "Home Air Conditioner"
-);
-
-pub struct MeteringPacket(Properties);
-convert_packet!(
-    code::POWER_DISTRIBUTION_BOARD_METERING,
-    MeteringPacket,
-    POWER_DISTRIBUTION_BOARD_METERING_CLASS,
-    "Power Distribution Board Metering"
-);
-
-pub struct FuelCellPacket(Properties);
-convert_packet!(
-    code::FUEL_CELL,
-    FuelCellPacket,
-    FUEL_CELL_CLASS,
-    "Fuel Cell"
-);
-
-pub struct InstantaneousWaterHeaterPacket(Properties);
-convert_packet!(
-    code::INSTANTANEOUS_WATER_HEATER,
-    InstantaneousWaterHeaterPacket,
-    INSTANTANEOUS_WATER_HEATER_CLASS,
-    "Instantaneous Water Heater"
-);
-
-pub struct GeneralLightingPacket(Properties);
-convert_packet!(
-    code::GENERAL_LIGHTING,
-    GeneralLightingPacket,
-    GENERAL_LIGHTING_CLASS,
-    "General Lighting"
-);
-
-pub struct MonoFunctionLightingPacket(Properties);
-convert_packet!(
-    code::MONO_FUNCTION_LIGHTING,
-    MonoFunctionLightingPacket,
-    MONO_FUNCTION_LIGHTING_CLASS,
-    "Mono Function Lighting"
-);
-
-pub struct LightingSystemPacket(Properties);
-convert_packet!(
-    code::LIGHTING_SYSTEM,
-    LightingSystemPacket,
-    LIGHTING_SYSTEM_CLASS,
-    "Lighting System"
-);
-
-pub struct ProfilePacket(Properties);
-convert_packet!(code::PROFILE, ProfilePacket, PROFILE_CLASS, "Node Profile");
-
-pub struct ControllerPacket(Properties);
-convert_packet!(
-    code::CONTROLLER,
-    ControllerPacket,
-    CONTROLLER_CLASS,
-    "Controller"
-);
+    fn cumulative_energy(&self, epc: u8) -> Option<u32> {
+        let edt: [u8; 4] = self.edt(epc)?.try_into().ok()?;
+        Some(u32::from_be_bytes(edt))
+    }
+
+    /// Measured cumulative amount of electric energy (normal direction) in Wh.
+    ///
+    /// Returns `None` if the packet does not contain a valid 0xE0 property.
+    pub fn cumulative_energy_normal(&self) -> Option<u32> {
+        self.cumulative_energy(Self::CUMULATIVE_ENERGY_NORMAL)
+    }
+
+    /// Measured cumulative amount of electric energy (reverse direction) in Wh.
+    ///
+    /// Returns `None` if the packet does not contain a valid 0xE3 property.
+    pub fn cumulative_energy_reverse(&self) -> Option<u32> {
+        self.cumulative_energy(Self::CUMULATIVE_ENERGY_REVERSE)
+    }
+
+    /// ECHONET objects connected to this PCS.
+    ///
+    /// The 0xE8 EDT consists of the number of devices followed by their EOJs.
+    /// Returns `None` if the packet does not contain a valid 0xE8 property.
+    pub fn connected_devices(&self) -> Option<Vec<EchonetObject>> {
+        decode_object_list(self.edt(Self::CONNECTED_DEVICES)?)
+    }
+}
 
 pub struct Controller;
 impl Controller {
@@ -375,263 +500,110 @@ impl fmt::Display for Controller {
 /// - X1: Class group code
 /// - X2: Class code
 /// - X3: Instance code
-#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+///
+/// Objects are ordered by class code and then instance code,
+/// so that they can be used as keys of maps.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
 pub struct EchonetObject {
-    // TODO: use `ElClass` instead.
     class: ClassCode,
     instance: u8,
 }
 
-impl From<[u8; 3]> for EchonetObject {
-    fn from(eobj: [u8; 3]) -> Self {
+impl EchonetObject {
+    /// Creates an ECHONET object from class and instance code.
+    pub fn new<T: Into<ClassCode>>(class: T, instance: u8) -> Self {
         Self {
-            class: ClassCode([eobj[0], eobj[1]]),
-            instance: eobj[2],
+            class: class.into(),
+            instance,
         }
     }
-}
 
-impl From<(ElClass, u8)> for EchonetObject {
-    fn from(value: (ElClass, u8)) -> Self {
-        let (class, instance) = value;
-        use code::*;
-        match class {
-            ElClass::HomeAC => Self {
-                class: HOME_AIR_CONDITIONER.into(),
-                instance,
-            },
-            ElClass::Hp => Self {
-                class: HP.into(),
-                instance,
-            },
-            ElClass::InstantaneousWaterHeater => Self {
-                class: INSTANTANEOUS_WATER_HEATER.into(),
-                instance,
-            },
-            ElClass::Pv => Self {
-                class: HOUSEHOLD_SOLAR_POWER.into(),
-                instance,
-            },
-            ElClass::FuelCell => Self {
-                class: FUEL_CELL.into(),
-                instance,
-            },
-            ElClass::Battery => Self {
-                class: STORAGE_BATTERY.into(),
-                instance,
-            },
-            ElClass::Evps => Self {
-                class: EVPS.into(),
-                instance,
-            },
-            ElClass::Metering => Self {
-                class: POWER_DISTRIBUTION_BOARD_METERING.into(),
-                instance,
-            },
-            ElClass::SmartMeter => Self {
-                class: SMART_METER.into(),
-                instance,
-            },
-            ElClass::MultiInputPCS => Self {
-                class: [0x02, 0xA5].into(),
-                instance,
-            },
-            ElClass::GeneralLighting => Self {
-                class: GENERAL_LIGHTING.into(),
-                instance,
-            },
-            ElClass::MonoFunctionLighting => Self {
-                class: MONO_FUNCTION_LIGHTING.into(),
-                instance,
-            },
-            ElClass::LightingSystem => Self {
-                class: LIGHTING_SYSTEM.into(),
-                instance,
-            },
-            ElClass::Controller => Self {
-                class: CONTROLLER.into(),
-                instance,
-            },
-            ElClass::Profile => Self {
-                class: PROFILE.into(),
-                instance,
-            },
-            ElClass::Unknown(code) => Self {
-                class: code.into(),
-                instance,
-            },
-        }
+    /// Class code (X1.X2).
+    pub fn class_code(&self) -> ClassCode {
+        self.class
     }
-}
 
-impl TryFrom<&[u8]> for EchonetObject {
-    type Error = crate::error::Error;
+    /// Class of the object.
+    pub fn class(&self) -> ElClass {
+        ElClass::from(self.class)
+    }
 
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let (_, eobj) = deserialize(value)?;
-        Ok(eobj)
+    /// Class group (X1).
+    pub fn group(&self) -> ClassGroup {
+        self.class.group()
     }
-}
 
-impl fmt::Display for EchonetObject {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use code::*;
-        let class = match self.class.0 {
-            HOME_AIR_CONDITIONER => "Home AC",
-            HP => "Heat pump",
-            INSTANTANEOUS_WATER_HEATER => "Instantaneous water heater",
-            HOUSEHOLD_SOLAR_POWER => "Household solar power",
-            FUEL_CELL => "Fuel cell",
-            STORAGE_BATTERY => "Storage battery",
-            EVPS => "V2H",
-            POWER_DISTRIBUTION_BOARD_METERING => "Power distribution Metering",
-            SMART_METER => "Smart meter",
-            GENERAL_LIGHTING => "General lighting",
-            MONO_FUNCTION_LIGHTING => "Mono function lighting",
-            LIGHTING_SYSTEM => "Lighting system",
-            CONTROLLER => "Controller",
-            PROFILE => "Profile",
-            _ => "Unknown",
-        };
-        write!(f, "{} [{} {:02X}]", class, self.class, self.instance)
+    /// Instance code (X3).
+    ///
+    /// 0x00 is used to specify all instances of the class.
+    pub fn instance(&self) -> u8 {
+        self.instance
     }
-}
 
-/// echonet-lite class representation.
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-pub enum ElClass {
-    HomeAC,
-    Hp,
-    InstantaneousWaterHeater,
-    Pv,
-    FuelCell,
-    Battery,
-    Evps,
-    Metering,
-    SmartMeter,
-    MultiInputPCS,
-    GeneralLighting,
-    MonoFunctionLighting,
-    LightingSystem,
-    Controller,
-    Profile,
-    Unknown([u8; 2]),
-}
+    /// Returns whether the object is the node profile object.
+    pub fn is_node_profile(&self) -> bool {
+        self.class.is_node_profile()
+    }
 
-impl Serialize for ElClass {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let raw = Into::<[u8; 2]>::into(*self);
-        let mut seq = serializer.serialize_tuple(2)?;
-        seq.serialize_element(&raw[0])?;
-        seq.serialize_element(&raw[1])?;
-        seq.end()
+    /// Returns whether the object is a device object.
+    pub fn is_device(&self) -> bool {
+        self.class.is_device()
     }
 }
 
-struct ElClassVisitor;
-impl<'de> Visitor<'de> for ElClassVisitor {
-    type Value = (u8, u8);
+impl From<[u8; 3]> for EchonetObject {
+    fn from(eobj: [u8; 3]) -> Self {
+        Self {
+            class: ClassCode([eobj[0], eobj[1]]),
+            instance: eobj[2],
+        }
+    }
+}
 
-    fn expecting(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
-        formatter.write_str("never failed")
+impl From<EchonetObject> for [u8; 3] {
+    fn from(eobj: EchonetObject) -> Self {
+        [eobj.class.0[0], eobj.class.0[1], eobj.instance]
     }
+}
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-    where
-        A: serde::de::SeqAccess<'de>,
-    {
-        let group: u8 = seq.next_element()?.unwrap();
-        let class: u8 = seq.next_element()?.unwrap();
-        Ok((group, class))
+impl From<(ElClass, u8)> for EchonetObject {
+    fn from(value: (ElClass, u8)) -> Self {
+        let (class, instance) = value;
+        Self::new(class, instance)
     }
 }
 
-impl<'de> Deserialize<'de> for ElClass {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let (group, class) = deserializer.deserialize_tuple(2, ElClassVisitor)?;
-        Ok(ElClass::from(&[group, class]))
+/// Parses hex notation of an ECHONET object such as `0x013001` or `013001`.
+impl FromStr for EchonetObject {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let eobj: [u8; 3] = parse_hex(s)?;
+        Ok(eobj.into())
     }
 }
 
-impl From<&[u8; 2]> for ElClass {
-    fn from(value: &[u8; 2]) -> Self {
-        use code::*;
-        use ElClass::*;
-        match *value {
-            HOME_AIR_CONDITIONER => HomeAC,
-            HP => Hp,
-            INSTANTANEOUS_WATER_HEATER => InstantaneousWaterHeater,
-            HOUSEHOLD_SOLAR_POWER => Pv,
-            FUEL_CELL => FuelCell,
-            STORAGE_BATTERY => Battery,
-            EVPS => Evps,
-            POWER_DISTRIBUTION_BOARD_METERING => Metering,
-            SMART_METER => SmartMeter,
-            [0x02, 0xA5] => MultiInputPCS,
-            GENERAL_LIGHTING => GeneralLighting,
-            MONO_FUNCTION_LIGHTING => MonoFunctionLighting,
-            LIGHTING_SYSTEM => LightingSystem,
-            CONTROLLER => Controller,
-            PROFILE => Profile,
-            _ => Unknown(*value),
-        }
+impl TryFrom<&[u8]> for EchonetObject {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let (_, eobj) = deserialize(value)?;
+        Ok(eobj)
     }
 }
 
-#[allow(clippy::from_over_into)]
-impl Into<[u8; 2]> for ElClass {
-    fn into(self) -> [u8; 2] {
-        use code::*;
-        use ElClass::*;
-        match self {
-            HomeAC => HOME_AIR_CONDITIONER,
-            Hp => HP,
-            InstantaneousWaterHeater => INSTANTANEOUS_WATER_HEATER,
-            Pv => HOUSEHOLD_SOLAR_POWER,
-            FuelCell => FUEL_CELL,
-            Battery => STORAGE_BATTERY,
-            Evps => EVPS,
-            Metering => POWER_DISTRIBUTION_BOARD_METERING,
-            SmartMeter => SMART_METER,
-            MultiInputPCS => [0x02, 0xA5],
-            GeneralLighting => GENERAL_LIGHTING,
-            MonoFunctionLighting => MONO_FUNCTION_LIGHTING,
-            LightingSystem => LIGHTING_SYSTEM,
-            Controller => CONTROLLER,
-            Profile => PROFILE,
-            Unknown(raw) => raw,
-        }
+impl fmt::Display for EchonetObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let class = ClassDef::builtin(self.class.0).map_or("Unknown", |class| class.short_name);
+        write!(f, "{} [{} {:02X}]", class, self.class, self.instance)
     }
 }
 
 impl From<EchonetObject> for ElClass {
     fn from(value: EchonetObject) -> Self {
-        use code::*;
-        use ElClass::*;
-        match value.class.0 {
-            HOME_AIR_CONDITIONER => HomeAC,
-            HP => Hp,
-            INSTANTANEOUS_WATER_HEATER => InstantaneousWaterHeater,
-            HOUSEHOLD_SOLAR_POWER => Pv,
-            FUEL_CELL => FuelCell,
-            STORAGE_BATTERY => Battery,
-            EVPS => Evps,
-            POWER_DISTRIBUTION_BOARD_METERING => Metering,
-            SMART_METER => SmartMeter,
-            [0x02, 0xA5] => MultiInputPCS,
-            GENERAL_LIGHTING => GeneralLighting,
-            MONO_FUNCTION_LIGHTING => MonoFunctionLighting,
-            LIGHTING_SYSTEM => LightingSystem,
-            CONTROLLER => Controller,
-            PROFILE => Profile,
-            _ => Unknown(value.class.0),
-        }
+        value.class()
     }
 }
 
@@ -663,6 +635,90 @@ mod test {
         );
     }
 
+    #[test]
+    fn multi_input_pcs_packet() {
+        use crate::{props, ElPacketBuilder, Property, ServiceCode};
+        use crate::{Edt, Properties};
+
+        let packet = ElPacketBuilder::new()
+            .seoj([0x02, 0xA5, 0x01])
+            .deoj([0x05, 0xFF, 0x01])
+            .esv(ServiceCode::GetRes)
+            .props(props!(
+                [0xE0, [0x00, 0x00, 0x01, 0xF4]],
+                [0xE3, [0x00, 0x00]],
+                [0xE8, [0x02, 0x02, 0x79, 0x01, 0x02, 0x7D, 0x01]]
+            ))
+            .build();
+        let ClassPacket::MultiInputPcs(pcs) = ClassPacket::from(packet) else {
+            panic!("expected multi input PCS packet");
+        };
+        assert_eq!(pcs.cumulative_energy_normal(), Some(500));
+        assert_eq!(pcs.cumulative_energy_reverse(), None);
+        assert_eq!(
+            pcs.connected_devices(),
+            Some(vec![
+                EchonetObject::from([0x02, 0x79, 0x01]),
+                EchonetObject::from([0x02, 0x7D, 0x01]),
+            ])
+        );
+    }
+
+    #[test]
+    fn class_packet_from_request() {
+        use crate::{bulk_read, ElPacketBuilder, Property, ServiceCode};
+        use crate::{Edt, Properties};
+
+        let request = ElPacketBuilder::new()
+            .seoj([0x05, 0xFF, 0x01])
+            .deoj([0x02, 0x88, 0x01])
+            .esv(ServiceCode::Get)
+            .props(bulk_read!(0xE7))
+            .build();
+        assert!(matches!(
+            ClassPacket::from(request.clone()),
+            ClassPacket::SmartMeter(_)
+        ));
+        assert!(SmartMeterPacket::try_from(request.clone()).is_ok());
+        assert!(ControllerPacket::try_from(request.clone()).is_err());
+
+        let response = request.create_response(ServiceCode::GetRes, bulk_read!(0xE7));
+        assert!(SmartMeterPacket::try_from(response).is_ok());
+    }
+
+    #[test]
+    fn echonet_object_accessors() {
+        let eobj: EchonetObject = "0x013001".parse().unwrap();
+        assert_eq!(eobj, EchonetObject::from([0x01, 0x30, 0x01]));
+        assert_eq!(eobj.class(), ElClass::HomeAC);
+        assert_eq!(eobj.group(), ClassGroup::AirConditioner);
+        assert_eq!(eobj.instance(), 1);
+        assert!(eobj.is_device());
+
+        let profile: EchonetObject = "0EF001".parse().unwrap();
+        assert!(profile.is_node_profile());
+        assert!(!profile.is_device());
+        assert!(eobj < profile);
+        assert!("0x0130".parse::<EchonetObject>().is_err());
+    }
+
+    #[test]
+    fn display_decoded_values() {
+        use crate::lib::ToString;
+        use crate::{props, Edt};
+
+        let packet = ClassPacket::new(
+            [0x02, 0x88, 0x01].into(),
+            props!([0x80, [0x30]], [0xE7, [0x00, 0x00, 0x01, 0xF4]]),
+        );
+        let display = packet.to_string();
+        assert!(display.contains("80: 30 \t\t[動作状態] ON\n"));
+        assert!(display.contains("E7: 00 00 01 F4 \t\t[瞬時電力計測値] 500 W\n"));
+
+        let display = packet.localized(Locale::En).to_string();
+        assert!(display.contains("[Measured instantaneous electric power] 500 W\n"));
+    }
+
     #[test]
     fn serialize_el_class() {
         let class = ElClass::HomeAC;
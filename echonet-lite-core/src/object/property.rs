@@ -0,0 +1,268 @@
+use super::{AccessRule, Locale, Localized, LocalizedName, ReleaseRange};
+use crate::lib::BTreeMap;
+use core::fmt;
+
+/// Definition of an ECHONET property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PropertyDef {
+    /// Name of the property.
+    pub name: LocalizedName,
+    /// How to decode the EDT of the property.
+    pub edt: EdtDef,
+    /// Appendix releases defining the property.
+    pub releases: ReleaseRange,
+    /// Whether the property accepts Get, Set and announcements.
+    pub access: AccessRule,
+}
+
+impl PropertyDef {
+    /// Creates a property definition whose EDT is not decoded.
+    pub const fn new(ja: &'static str, en: &'static str) -> Self {
+        Self {
+            name: LocalizedName::new(ja, en),
+            edt: EdtDef::Raw,
+            releases: ReleaseRange::ALL,
+            access: AccessRule::ANY,
+        }
+    }
+
+    /// Sets the EDT to be one of `states`.
+    pub const fn states(mut self, states: &'static [(u8, LocalizedName)]) -> Self {
+        self.edt = EdtDef::State(states);
+        self
+    }
+
+    /// Sets the EDT to be a number.
+    pub const fn number(mut self, number: NumberDef) -> Self {
+        self.edt = EdtDef::Number(number);
+        self
+    }
+
+    /// Sets the Appendix releases defining the property.
+    pub const fn releases(mut self, releases: ReleaseRange) -> Self {
+        self.releases = releases;
+        self
+    }
+
+    /// Sets the access rules of the property.
+    pub const fn access(mut self, access: AccessRule) -> Self {
+        self.access = access;
+        self
+    }
+
+    /// Decodes `edt` into a human readable value.
+    ///
+    /// Returns `None` if the EDT is not decodable or does not match the definition.
+    pub fn decode(&self, edt: &[u8]) -> Option<Value> {
+        self.edt.decode(edt)
+    }
+}
+
+/// Property definitions of a class.
+#[derive(Debug)]
+pub enum PropertyMap {
+    /// Map generated at compile time, e.g. by mra-reader.
+    Static(&'static phf::Map<u8, PropertyDef>),
+    /// Map built at runtime, e.g. from the MRA JSON.
+    Dynamic(BTreeMap<u8, PropertyDef>),
+}
+
+impl PropertyMap {
+    /// Finds the definition of `epc`.
+    pub fn get(&self, epc: u8) -> Option<&PropertyDef> {
+        match self {
+            PropertyMap::Static(map) => map.get(&epc),
+            PropertyMap::Dynamic(map) => map.get(&epc),
+        }
+    }
+
+    /// Number of the properties.
+    pub fn len(&self) -> usize {
+        match self {
+            PropertyMap::Static(map) => map.len(),
+            PropertyMap::Dynamic(map) => map.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Definition of the EDT format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdtDef {
+    /// Not decoded
+    Raw,
+    /// 1 byte enumerated state
+    State(&'static [(u8, LocalizedName)]),
+    /// Big endian integer
+    Number(NumberDef),
+}
+
+impl EdtDef {
+    /// Decodes `edt` into a human readable value.
+    pub fn decode(&self, edt: &[u8]) -> Option<Value> {
+        match self {
+            EdtDef::Raw => None,
+            EdtDef::State(states) => match edt {
+                [state] => states
+                    .iter()
+                    .find(|(code, _)| code == state)
+                    .map(|(_, name)| Value::State(*name)),
+                _ => None,
+            },
+            EdtDef::Number(number) => number.decode(edt).map(Value::Number),
+        }
+    }
+}
+
+/// Definition of a numeric EDT.
+///
+/// The decoded value is `raw * 10^exponent` in `unit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberDef {
+    /// Size of the EDT in bytes (1, 2 or 4).
+    pub size: usize,
+    /// Whether the EDT is a signed integer.
+    pub signed: bool,
+    /// Power of ten multiplied to the raw value.
+    pub exponent: i8,
+    /// Unit of the value.
+    pub unit: &'static str,
+}
+
+impl NumberDef {
+    /// Creates an unsigned number definition.
+    pub const fn unsigned(size: usize, exponent: i8, unit: &'static str) -> Self {
+        Self {
+            size,
+            signed: false,
+            exponent,
+            unit,
+        }
+    }
+
+    /// Creates a signed number definition.
+    pub const fn signed(size: usize, exponent: i8, unit: &'static str) -> Self {
+        Self {
+            size,
+            signed: true,
+            exponent,
+            unit,
+        }
+    }
+
+    /// Decodes `edt` into a number.
+    pub fn decode(&self, edt: &[u8]) -> Option<Number> {
+        if edt.len() != self.size || !matches!(self.size, 1 | 2 | 4) {
+            return None;
+        }
+        let raw = edt.iter().fold(0u64, |acc, byte| (acc << 8) | *byte as u64);
+        let raw = if self.signed {
+            let shift = 64 - self.size * 8;
+            ((raw << shift) as i64) >> shift
+        } else {
+            raw as i64
+        };
+        Some(Number {
+            raw,
+            exponent: self.exponent,
+            unit: self.unit,
+        })
+    }
+}
+
+/// A decoded EDT value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value {
+    /// Enumerated state
+    State(LocalizedName),
+    /// Number with unit
+    Number(Number),
+}
+
+impl Value {
+    pub(crate) fn fmt_localized(&self, f: &mut fmt::Formatter, locale: Locale) -> fmt::Result {
+        match self {
+            Value::State(name) => f.write_str(name.get(locale)),
+            Value::Number(number) => write!(f, "{number}"),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, Locale::default())
+    }
+}
+
+impl fmt::Display for Localized<'_, Value> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt_localized(f, self.locale)
+    }
+}
+
+/// A decimal number with unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    /// Raw integer value in the EDT.
+    pub raw: i64,
+    /// Power of ten multiplied to the raw value.
+    pub exponent: i8,
+    /// Unit of the value.
+    pub unit: &'static str,
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.exponent >= 0 {
+            write!(f, "{}", self.raw * 10i64.pow(self.exponent as u32))?;
+        } else {
+            let digits = self.exponent.unsigned_abs() as usize;
+            let scale = 10u64.pow(digits as u32);
+            let abs = self.raw.unsigned_abs();
+            let sign = if self.raw < 0 { "-" } else { "" };
+            write!(f, "{sign}{}.{:0digits$}", abs / scale, abs % scale)?;
+        }
+        if !self.unit.is_empty() {
+            write!(f, " {}", self.unit)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lib::ToString;
+
+    #[test]
+    fn decode_state() {
+        static ON_OFF: [(u8, LocalizedName); 2] = [
+            (0x30, LocalizedName::new("ON", "ON")),
+            (0x31, LocalizedName::new("OFF", "OFF")),
+        ];
+        let def = PropertyDef::new("動作状態", "Operation status").states(&ON_OFF);
+        assert_eq!(def.decode(&[0x30]), Some(Value::State(ON_OFF[0].1)));
+        assert_eq!(def.decode(&[0x32]), None);
+        assert_eq!(def.decode(&[0x30, 0x30]), None);
+    }
+
+    #[test]
+    fn decode_number() {
+        let def = NumberDef::signed(4, 0, "W");
+        let number = def.decode(&[0xFF, 0xFF, 0xFE, 0x0C]).unwrap();
+        assert_eq!(number.raw, -500);
+        assert_eq!(number.to_string(), "-500 W");
+        assert_eq!(def.decode(&[0x00, 0x01]), None);
+
+        let def = NumberDef::unsigned(4, -3, "kWh");
+        let number = def.decode(&[0x00, 0x00, 0x30, 0x39]).unwrap();
+        assert_eq!(number.to_string(), "12.345 kWh");
+
+        let def = NumberDef::signed(2, -1, "℃");
+        let number = def.decode(&[0xFF, 0xFB]).unwrap();
+        assert_eq!(number.to_string(), "-0.5 ℃");
+    }
+}
@@ -1,453 +1,531 @@
+use super::{Access, AccessRule, LocalizedName, NumberDef, PropertyDef, ReleaseRange};
 use phf::phf_map;
 
-pub static SUPER_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0x80u8 => "動作状態",
-    0x81u8 => "設置場所",
-    0x82u8 => "規格version",
-    0x83u8 => "識別番号",
-    0x84u8 => "瞬時消費電力",
-    0x85u8 => "積算消費電力",
-    0x86u8 => "メーカ異常コード",
-    0x87u8 => "電流制限設定",
-    0x88u8 => "異常発生状態",
-    0x89u8 => "異常内容",
-    0x8Au8 => "メーカコード",
-    0x8Bu8 => "事業場コード",
-    0x8Cu8 => "商品コード",
-    0x8Du8 => "製造番号",
-    0x8Eu8 => "製造年月日",
-    0x8Fu8 => "節電動作設定",
-    0x93u8 => "遠隔操作設定",
-    0x97u8 => "現在時刻設定",
-    0x98u8 => "現在年月日設定",
-    0x99u8 => "電力制限設定",
-    0x9Au8 => "積算運転時間",
-    0x9Du8 => "状変アナウンスプロパティマップ",
-    0x9Eu8 => "Setプロパティマップ",
-    0x9Fu8 => "Getプロパティマップ",
+const ON_OFF: [(u8, LocalizedName); 2] = [
+    (0x30, LocalizedName::new("ON", "ON")),
+    (0x31, LocalizedName::new("OFF", "OFF")),
+];
+
+const FAULT: [(u8, LocalizedName); 2] = [
+    (0x41, LocalizedName::new("異常あり", "Fault")),
+    (0x42, LocalizedName::new("異常なし", "No fault")),
+];
+
+const POWER_SAVING: [(u8, LocalizedName); 2] = [
+    (0x41, LocalizedName::new("節電動作中", "Power saving")),
+    (0x42, LocalizedName::new("通常動作中", "Normal")),
+];
+
+const BATTERY_OPERATION_MODE: [(u8, LocalizedName); 9] = [
+    (0x40, LocalizedName::new("その他", "Other")),
+    (0x41, LocalizedName::new("急速充電", "Rapid charging")),
+    (0x42, LocalizedName::new("充電", "Charging")),
+    (0x43, LocalizedName::new("放電", "Discharging")),
+    (0x44, LocalizedName::new("待機", "Standby")),
+    (0x45, LocalizedName::new("テスト", "Test")),
+    (0x46, LocalizedName::new("自動", "Automatic")),
+    (0x48, LocalizedName::new("再起動", "Restart")),
+    (
+        0x49,
+        LocalizedName::new("実効容量再計算処理", "Effective capacity recalculation"),
+    ),
+];
+
+const AC_OPERATION_MODE: [(u8, LocalizedName); 6] = [
+    (0x40, LocalizedName::new("その他", "Other")),
+    (0x41, LocalizedName::new("自動", "Automatic")),
+    (0x42, LocalizedName::new("冷房", "Cooling")),
+    (0x43, LocalizedName::new("暖房", "Heating")),
+    (0x44, LocalizedName::new("除湿", "Dehumidification")),
+    (0x45, LocalizedName::new("送風", "Air circulator")),
+];
+
+pub static SUPER_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0x80u8 => PropertyDef::new("動作状態", "Operation status").access(AccessRule::new(Access::Required, Access::Optional, Access::Required)).states(&ON_OFF),
+    0x81u8 => PropertyDef::new("設置場所", "Installation location").access(AccessRule::new(Access::Required, Access::Required, Access::Required)),
+    0x82u8 => PropertyDef::new("規格version", "Standard version information").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0x83u8 => PropertyDef::new("識別番号", "Identification number").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0x84u8 => PropertyDef::new("瞬時消費電力", "Measured instantaneous power consumption").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(2, 0, "W")),
+    0x85u8 => PropertyDef::new("積算消費電力", "Measured cumulative electric energy consumption").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(4, -3, "kWh")),
+    0x86u8 => PropertyDef::new("メーカ異常コード", "Manufacturer's fault code").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0x87u8 => PropertyDef::new("電流制限設定", "Current limit setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x88u8 => PropertyDef::new("異常発生状態", "Fault status").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::Required)).states(&FAULT),
+    0x89u8 => PropertyDef::new("異常内容", "Fault description").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0x8Au8 => PropertyDef::new("メーカコード", "Manufacturer code").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0x8Bu8 => PropertyDef::new("事業場コード", "Business facility code").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0x8Cu8 => PropertyDef::new("商品コード", "Product code").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0x8Du8 => PropertyDef::new("製造番号", "Production number").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0x8Eu8 => PropertyDef::new("製造年月日", "Production date").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0x8Fu8 => PropertyDef::new("節電動作設定", "Power-saving operation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)).states(&POWER_SAVING),
+    0x93u8 => PropertyDef::new("遠隔操作設定", "Remote control setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x97u8 => PropertyDef::new("現在時刻設定", "Current time setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x98u8 => PropertyDef::new("現在年月日設定", "Current date setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x99u8 => PropertyDef::new("電力制限設定", "Power limit setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x9Au8 => PropertyDef::new("積算運転時間", "Cumulative operating time").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0x9Du8 => PropertyDef::new("状変アナウンスプロパティマップ", "Status change announcement property map").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0x9Eu8 => PropertyDef::new("Setプロパティマップ", "Set property map").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0x9Fu8 => PropertyDef::new("Getプロパティマップ", "Get property map").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
 };
 
-pub static PROFILE_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0xBFu8 => "個体識別情報",
-    0xD3u8 => "自ノードインスタンス数",
-    0xD4u8 => "自ノードクラス数",
-    0xD5u8 => "インスタンスリスト通知",
-    0xD6u8 => "自ノードインスタンスリストS",
-    0xD7u8 => "自ノードクラスリストS",
+pub static PROFILE_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xBFu8 => PropertyDef::new("個体識別情報", "Unique identifier data").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD3u8 => PropertyDef::new("自ノードインスタンス数", "Number of self-node instances").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xD4u8 => PropertyDef::new("自ノードクラス数", "Number of self-node classes").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xD5u8 => PropertyDef::new("インスタンスリスト通知", "Instance list notification").access(AccessRule::new(Access::NotApplicable, Access::NotApplicable, Access::Required)),
+    0xD6u8 => PropertyDef::new("自ノードインスタンスリストS", "Self-node instance list S").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xD7u8 => PropertyDef::new("自ノードクラスリストS", "Self-node class list S").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
 };
 
-pub static SMART_METER_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0xD3u8 => "係数",
-    0xD7u8 => "積算電力量有効桁数",
-    0xE0u8 => "積算電力量計測値（正方向計測値）",
-    0xE1u8 => "積算電力量単位（正方向、逆方向計測値）",
-    0xE2u8 => "積算電力量計測値履歴1（正方向計測値）",
-    0xE3u8 => "積算電力量計測値（逆方向計測値）",
-    0xE4u8 => "積算電力量計測値履歴1（逆方向計測値）",
-    0xE5u8 => "積算履歴収集日",
-    0xE7u8 => "瞬時電力計測値",
-    0xE8u8 => "瞬時電流計測値",
-    0xEAu8 => "定時積算電力量計測値（正方向計測値）",
-    0xEBu8 => "定時積算電力量計測値（逆方向計測値）",
-    0xECu8 => "積算電力量計測値履歴2（正方向、逆方向計測値）",
-    0xEDu8 => "積算履歴収集日2",
+pub const PROFILE_RELEASES: ReleaseRange = ReleaseRange::since(b'A');
+
+pub static SMART_METER_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xD3u8 => PropertyDef::new("係数", "Coefficient").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD7u8 => PropertyDef::new("積算電力量有効桁数", "Number of effective digits for cumulative amounts of electric energy").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xE0u8 => PropertyDef::new("積算電力量計測値（正方向計測値）", "Measured cumulative amount of electric energy (normal direction)").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xE1u8 => PropertyDef::new("積算電力量単位（正方向、逆方向計測値）", "Unit for cumulative amounts of electric energy (normal and reverse directions)").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xE2u8 => PropertyDef::new("積算電力量計測値履歴1（正方向計測値）", "Historical data of measured cumulative amounts of electric energy 1 (normal direction)").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xE3u8 => PropertyDef::new("積算電力量計測値（逆方向計測値）", "Measured cumulative amounts of electric energy (reverse direction)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE4u8 => PropertyDef::new("積算電力量計測値履歴1（逆方向計測値）", "Historical data of measured cumulative amounts of electric energy 1 (reverse direction)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE5u8 => PropertyDef::new("積算履歴収集日", "Day for which the historical data of measured cumulative amounts of electric energy is to be retrieved 1").access(AccessRule::new(Access::Required, Access::Required, Access::NotApplicable)),
+    0xE7u8 => PropertyDef::new("瞬時電力計測値", "Measured instantaneous electric power").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::signed(4, 0, "W")),
+    0xE8u8 => PropertyDef::new("瞬時電流計測値", "Measured instantaneous currents").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xEAu8 => PropertyDef::new("定時積算電力量計測値（正方向計測値）", "Cumulative amounts of electric energy measured at fixed time (normal direction)").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xEBu8 => PropertyDef::new("定時積算電力量計測値（逆方向計測値）", "Cumulative amounts of electric energy measured at fixed time (reverse direction)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xECu8 => PropertyDef::new("積算電力量計測値履歴2（正方向、逆方向計測値）", "Historical data of measured cumulative amounts of electric energy 2 (normal and reverse directions)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEDu8 => PropertyDef::new("積算履歴収集日2", "Day for which the historical data of measured cumulative amounts of electric energy is to be retrieved 2").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
 };
 
-pub static HOUSEHOLD_SOLAR_POWER_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0xA0u8 => "出力制御設定１",
-    0xA1u8 => "出力制御設定２",
-    0xA2u8 => "余剰買取制御機能設定",
-    0xB0u8 => "出力制御スケジュール",
-    0xB1u8 => "次回アクセス日時",
-    0xB2u8 => "余剰買取制御機能タイプ",
-    0xB3u8 => "出力変化時間設定値",
-    0xB4u8 => "上限クリップ設定値",
-    0xC0u8 => "運転力率設定値",
-    0xC1u8 => "FIT契約タイプ",
-    0xC2u8 => "自家消費タイプ",
-    0xC3u8 => "設備認定容量",
-    0xC4u8 => "換算係数",
-    0xD0u8 => "系統連系状態",
-    0xD1u8 => "出力抑制状態",
-    0xE0u8 => "瞬時発電電力計測値",
-    0xE1u8 => "積算発電電力量計測値",
-    0xE2u8 => "積算発電電力量リセット設定",
-    0xE3u8 => "積算売電電力量計測値",
-    0xE4u8 => "積算売電電力量リセット設定",
-    0xE5u8 => "発電電力制限設定１",
-    0xE6u8 => "発電電力制限設定２",
-    0xE7u8 => "売電電力制限設定",
-    0xE8u8 => "定格発電電力値（系統連系時",
-    0xE9u8 => "定格発電電力値（独立時",
+pub const SMART_METER_RELEASES: ReleaseRange = ReleaseRange::since(b'B');
+
+pub static HOUSEHOLD_SOLAR_POWER_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xA0u8 => PropertyDef::new("出力制御設定１", "Output power control setting 1").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xA1u8 => PropertyDef::new("出力制御設定２", "Output power control setting 2").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xA2u8 => PropertyDef::new("余剰買取制御機能設定", "Function to control purchase surplus electricity setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB0u8 => PropertyDef::new("出力制御スケジュール", "Output power controlling schedule").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB1u8 => PropertyDef::new("次回アクセス日時", "Next access date and time").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB2u8 => PropertyDef::new("余剰買取制御機能タイプ", "Type for function to control purchase surplus electricity").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB3u8 => PropertyDef::new("出力変化時間設定値", "Output power change time setting value").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB4u8 => PropertyDef::new("上限クリップ設定値", "Upper limit clip setting value").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC0u8 => PropertyDef::new("運転力率設定値", "Operation power factor setting value").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC1u8 => PropertyDef::new("FIT契約タイプ", "FIT contract type").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC2u8 => PropertyDef::new("自家消費タイプ", "Self-consumption type").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC3u8 => PropertyDef::new("設備認定容量", "Capacity approved by equipment").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC4u8 => PropertyDef::new("換算係数", "Conversion coefficient").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD0u8 => PropertyDef::new("系統連系状態", "System interconnected type").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD1u8 => PropertyDef::new("出力抑制状態", "Output power restraint status").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::Optional)),
+    0xE0u8 => PropertyDef::new("瞬時発電電力計測値", "Measured instantaneous amount of electricity generated").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(2, 0, "W")),
+    0xE1u8 => PropertyDef::new("積算発電電力量計測値", "Measured cumulative amount of electric energy generated").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(4, -3, "kWh")),
+    0xE2u8 => PropertyDef::new("積算発電電力量リセット設定", "Resetting cumulative amount of electric energy generated").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
+    0xE3u8 => PropertyDef::new("積算売電電力量計測値", "Measured cumulative amount of electric energy sold").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(4, -3, "kWh")),
+    0xE4u8 => PropertyDef::new("積算売電電力量リセット設定", "Resetting cumulative amount of electric energy sold").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
+    0xE5u8 => PropertyDef::new("発電電力制限設定１", "Power generation output limit setting 1").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE6u8 => PropertyDef::new("発電電力制限設定２", "Power generation output limit setting 2").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE7u8 => PropertyDef::new("売電電力制限設定", "Limit setting for the amount of electricity sold").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE8u8 => PropertyDef::new("定格発電電力値（系統連系時", "Rated power generation output (System-interconnected)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE9u8 => PropertyDef::new("定格発電電力値（独立時", "Rated power generation output (Independent)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
 };
 
-pub static STORAGE_BATTERY_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0xA0u8 => "AC実効容量（充電）",
-    0xA1u8 => "AC実効容量（放電）",
-    0xA2u8 => "AC充電可能容量",
-    0xA3u8 => "AC放電可能容量",
-    0xA4u8 => "AC充電可能量",
-    0xA5u8 => "AC放電可能量",
-    0xA6u8 => "AC充電上限設定",
-    0xA7u8 => "AC放電下限設定",
-    0xA8u8 => "AC積算充電電力量計測値",
-    0xA9u8 => "AC積算放電電力量計測値",
-    0xAAu8 => "AC充電量設定値",
-    0xABu8 => "AC放電量設定値",
-    0xC1u8 => "充電方式",
-    0xC2u8 => "放電方式",
-    0xC8u8 => "最小最大充電電力値",
-    0xC9u8 => "最小最大放電電力値",
-    0xCAu8 => "最小最大充電電流値",
-    0xCBu8 => "最小最大放電電流値",
-    0xCCu8 => "再連系許可設定",
-    0xCDu8 => "運転許可設定",
-    0xCEu8 => "自立運転許可設定",
-    0xCFu8 => "運転動作状態",
-    0xC7u8 => "AC定格電力量",
-    0xD0u8 => "定格電力量",
-    0xD1u8 => "定格容量",
-    0xD2u8 => "定格電圧",
-    0xD3u8 => "瞬時充放電電力計測値",
-    0xD4u8 => "瞬時充放電電流計測値",
-    0xD5u8 => "瞬時充放電電圧計測値",
-    0xD6u8 => "積算放電電力量計測値",
-    0xD7u8 => "積算放電電力量リセット設定",
-    0xD8u8 => "積算充電電力量計測値",
-    0xD9u8 => "積算充電電力量リセット設定",
-    0xDAu8 => "運転モード設定",
-    0xDBu8 => "系統連系状態",
-    0xDCu8 => "最小最大充電電力値（独立時）",
-    0xDDu8 => "最小最大放電電力値（独立時）",
-    0xDEu8 => "最小最大充電電流値（独立時）",
-    0xDFu8 => "最小最大放電電流値（独立時）",
-    0xE0u8 => "充放電量設定値1",
-    0xE1u8 => "充放電量設定値2",
-    0xE2u8 => "蓄電残量1",
-    0xE3u8 => "蓄電残量2",
-    0xE4u8 => "蓄電残量3",
-    0xE5u8 => "劣化状態",
-    0xE6u8 => "蓄電池タイプ",
-    0xE7u8 => "充電量設定値1",
-    0xE8u8 => "放電量設定値1",
-    0xE9u8 => "充電量設定値2",
-    0xEAu8 => "放電量設定値2",
-    0xEBu8 => "充電電力設定値",
-    0xECu8 => "放電電力設定値",
-    0xEDu8 => "充電電流設定値",
-    0xEEu8 => "放電電流設定値",
-    0xEFu8 => "定格電圧（独立時）",
+pub const HOUSEHOLD_SOLAR_POWER_RELEASES: ReleaseRange = ReleaseRange::since(b'A');
+
+pub static STORAGE_BATTERY_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xA0u8 => PropertyDef::new("AC実効容量（充電）", "AC effective capacity (charging)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xA1u8 => PropertyDef::new("AC実効容量（放電）", "AC effective capacity (discharging)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xA2u8 => PropertyDef::new("AC充電可能容量", "AC chargeable capacity").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xA3u8 => PropertyDef::new("AC放電可能容量", "AC dischargeable capacity").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xA4u8 => PropertyDef::new("AC充電可能量", "AC chargeable electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xA5u8 => PropertyDef::new("AC放電可能量", "AC dischargeable electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xA6u8 => PropertyDef::new("AC充電上限設定", "AC charge upper limit setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xA7u8 => PropertyDef::new("AC放電下限設定", "AC discharge lower limit setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xA8u8 => PropertyDef::new("AC積算充電電力量計測値", "AC measured cumulative charging electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xA9u8 => PropertyDef::new("AC積算放電電力量計測値", "AC measured cumulative discharging electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xAAu8 => PropertyDef::new("AC充電量設定値", "AC charge amount setting value").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xABu8 => PropertyDef::new("AC放電量設定値", "AC discharge amount setting value").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xC1u8 => PropertyDef::new("充電方式", "Charging method").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC2u8 => PropertyDef::new("放電方式", "Discharging method").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC8u8 => PropertyDef::new("最小最大充電電力値", "Minimum/maximum charging electric power").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC9u8 => PropertyDef::new("最小最大放電電力値", "Minimum/maximum discharging electric power").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCAu8 => PropertyDef::new("最小最大充電電流値", "Minimum/maximum charging current").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCBu8 => PropertyDef::new("最小最大放電電流値", "Minimum/maximum discharging current").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCCu8 => PropertyDef::new("再連系許可設定", "Re-interconnection permission setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xCDu8 => PropertyDef::new("運転許可設定", "Operation permission setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xCEu8 => PropertyDef::new("自立運転許可設定", "Independent operation permission setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xCFu8 => PropertyDef::new("運転動作状態", "Working operation status").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::Required)).states(&BATTERY_OPERATION_MODE),
+    0xC7u8 => PropertyDef::new("AC定格電力量", "AC rated electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).releases(ReleaseRange::since(b'J')),
+    0xD0u8 => PropertyDef::new("定格電力量", "Rated electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD1u8 => PropertyDef::new("定格容量", "Rated capacity").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD2u8 => PropertyDef::new("定格電圧", "Rated voltage").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD3u8 => PropertyDef::new("瞬時充放電電力計測値", "Measured instantaneous charging/discharging electric power").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::signed(4, 0, "W")),
+    0xD4u8 => PropertyDef::new("瞬時充放電電流計測値", "Measured instantaneous charging/discharging current").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD5u8 => PropertyDef::new("瞬時充放電電圧計測値", "Measured instantaneous charging/discharging voltage").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD6u8 => PropertyDef::new("積算放電電力量計測値", "Measured cumulative discharging electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(4, -3, "kWh")),
+    0xD7u8 => PropertyDef::new("積算放電電力量リセット設定", "Measured cumulative discharging electric energy reset setting").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
+    0xD8u8 => PropertyDef::new("積算充電電力量計測値", "Measured cumulative charging electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(4, -3, "kWh")),
+    0xD9u8 => PropertyDef::new("積算充電電力量リセット設定", "Measured cumulative charging electric energy reset setting").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
+    0xDAu8 => PropertyDef::new("運転モード設定", "Operation mode setting").access(AccessRule::new(Access::Required, Access::Required, Access::Required)).states(&BATTERY_OPERATION_MODE),
+    0xDBu8 => PropertyDef::new("系統連系状態", "System-interconnected type").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xDCu8 => PropertyDef::new("最小最大充電電力値（独立時）", "Minimum/maximum charging power (Independent)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDDu8 => PropertyDef::new("最小最大放電電力値（独立時）", "Minimum/maximum discharging power (Independent)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDEu8 => PropertyDef::new("最小最大充電電流値（独立時）", "Minimum/maximum charging current (Independent)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDFu8 => PropertyDef::new("最小最大放電電流値（独立時）", "Minimum/maximum discharging current (Independent)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE0u8 => PropertyDef::new("充放電量設定値1", "Charging/discharging amount setting 1").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE1u8 => PropertyDef::new("充放電量設定値2", "Charging/discharging amount setting 2").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE2u8 => PropertyDef::new("蓄電残量1", "Remaining stored electricity 1").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xE3u8 => PropertyDef::new("蓄電残量2", "Remaining stored electricity 2").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE4u8 => PropertyDef::new("蓄電残量3", "Remaining stored electricity 3").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(1, 0, "%")),
+    0xE5u8 => PropertyDef::new("劣化状態", "Battery state of health").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE6u8 => PropertyDef::new("蓄電池タイプ", "Battery type").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xE7u8 => PropertyDef::new("充電量設定値1", "Charging amount setting 1").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE8u8 => PropertyDef::new("放電量設定値1", "Discharging amount setting 1").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE9u8 => PropertyDef::new("充電量設定値2", "Charging amount setting 2").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEAu8 => PropertyDef::new("放電量設定値2", "Discharging amount setting 2").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEBu8 => PropertyDef::new("充電電力設定値", "Charging electric power setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xECu8 => PropertyDef::new("放電電力設定値", "Discharging electric power setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEDu8 => PropertyDef::new("充電電流設定値", "Charging current setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEEu8 => PropertyDef::new("放電電流設定値", "Discharging current setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEFu8 => PropertyDef::new("定格電圧（独立時）", "Rated voltage (Independent)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+};
+
+pub const STORAGE_BATTERY_RELEASES: ReleaseRange = ReleaseRange::since(b'A');
+
+pub static EVPS_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xC0u8 => PropertyDef::new("車載電池の放電可能容量値1", "Dischargeable capacity of vehicle mounted battery 1").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC1u8 => PropertyDef::new("車載電池の放電可能容量値2", "Dischargeable capacity of vehicle mounted battery 2").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC2u8 => PropertyDef::new("車載電池の放電可能残容量1", "Remaining dischargeable capacity of vehicle mounted battery 1").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xC3u8 => PropertyDef::new("車載電池の放電可能残容量2", "Remaining dischargeable capacity of vehicle mounted battery 2").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC4u8 => PropertyDef::new("車載電池の放電可能残容量3", "Remaining dischargeable capacity of vehicle mounted battery 3").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC5u8 => PropertyDef::new("定格充電能力", "Rated charge capacity").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC6u8 => PropertyDef::new("定格放電能力", "Rated discharge capacity").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC7u8 => PropertyDef::new("車両接続・充放電可否状態", "Vehicle connection and chargeable/dischargeable status").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::Required)),
+    0xC8u8 => PropertyDef::new("最小最大充電電力値", "Minimum/maximum charging electric power").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xC9u8 => PropertyDef::new("最小最大放電電力値", "Minimum/maximum discharging electric power").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xCAu8 => PropertyDef::new("最小最大充電電流値", "Minimum/maximum charging current").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCBu8 => PropertyDef::new("最小最大放電電流値", "Minimum/maximum discharging current").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCCu8 => PropertyDef::new("充放電器タイプ", "Charger/discharger type").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xCDu8 => PropertyDef::new("車両接続確認", "Vehicle connection confirmation").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
+    0xCEu8 => PropertyDef::new("車載電池の充電可能容量値", "Chargeable capacity of vehicle mounted battery").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCFu8 => PropertyDef::new("車載電池の充電可能残容量値", "Remaining chargeable capacity of vehicle mounted battery").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD0u8 => PropertyDef::new("車載電池の使用容量値1", "Used capacity of vehicle mounted battery 1").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD1u8 => PropertyDef::new("車載電池の使用容量値2", "Used capacity of vehicle mounted battery 2").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD2u8 => PropertyDef::new("定格電圧", "Rated voltage").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD3u8 => PropertyDef::new("瞬時充放電電力計測値", "Measured instantaneous charging/discharging electric power").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::signed(4, 0, "W")),
+    0xD4u8 => PropertyDef::new("瞬時充放電電流計測値", "Measured instantaneous charging/discharging current").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD5u8 => PropertyDef::new("瞬時充放電電圧計測値", "Measured instantaneous charging/discharging voltage").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD6u8 => PropertyDef::new("積算放電電力量計測値", "Measured cumulative amount of discharging electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD7u8 => PropertyDef::new("積算放電電力量リセット設定", "Cumulative amount of discharging electric energy reset setting").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
+    0xD8u8 => PropertyDef::new("積算充電電力量計測値", "Measured cumulative amount of charging electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD9u8 => PropertyDef::new("積算充電電力量リセット設定", "Cumulative amount of charging electric energy reset setting").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
+    0xDAu8 => PropertyDef::new("運転モード設定", "Operation mode setting").access(AccessRule::new(Access::Required, Access::Required, Access::Required)),
+    0xDBu8 => PropertyDef::new("系統連系状態", "System interconnected type").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xDCu8 => PropertyDef::new("充電方式", "Charging method").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xDDu8 => PropertyDef::new("放電方式", "Discharging method").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xDEu8 => PropertyDef::new("買電電力設定値", "Purchasing electric power setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xDFu8 => PropertyDef::new("再連系許可設定", "Re-interconnection permission setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE2u8 => PropertyDef::new("車載電池の電池残容量1", "Remaining battery capacity of vehicle mounted battery 1").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xE3u8 => PropertyDef::new("車載電池の電池残容量2", "Remaining battery capacity of vehicle mounted battery 2").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE4u8 => PropertyDef::new("車載電池の電池残容量3", "Remaining battery capacity of vehicle mounted battery 3").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(1, 0, "%")),
+    0xE5u8 => PropertyDef::new("メンテナンス状態", "Maintenance status").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE6u8 => PropertyDef::new("車両ID", "Vehicle ID").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE7u8 => PropertyDef::new("充電量設定値1", "Charging amount setting 1").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE9u8 => PropertyDef::new("充電量設定値2", "Charging amount setting 2").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEAu8 => PropertyDef::new("放電量設定値", "Discharging amount setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEBu8 => PropertyDef::new("充電電力設定値", "Charging electric power setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xECu8 => PropertyDef::new("放電電力設定値", "Discharging electric power setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEDu8 => PropertyDef::new("充電電流設定値", "Charging current setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEEu8 => PropertyDef::new("放電電流設定値", "Discharging current setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEFu8 => PropertyDef::new("定格電圧（独立時）", "Rated voltage (Independent)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
 };
 
-pub static EVPS_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0xC0u8 => "車載電池の放電可能容量値1",
-    0xC1u8 => "車載電池の放電可能容量値2",
-    0xC2u8 => "車載電池の放電可能残容量1",
-    0xC3u8 => "車載電池の放電可能残容量2",
-    0xC4u8 => "車載電池の放電可能残容量3",
-    0xC5u8 => "定格充電能力",
-    0xC6u8 => "定格放電能力",
-    0xC7u8 => "車両接続・充放電可否状態",
-    0xC8u8 => "最小最大充電電力値",
-    0xC9u8 => "最小最大放電電力値",
-    0xCAu8 => "最小最大充電電流値",
-    0xCBu8 => "最小最大放電電流値",
-    0xCCu8 => "充放電器タイプ",
-    0xCDu8 => "車両接続確認",
-    0xCEu8 => "車載電池の充電可能容量値",
-    0xCFu8 => "車載電池の充電可能残容量値",
-    0xD0u8 => "車載電池の使用容量値1",
-    0xD1u8 => "車載電池の使用容量値2",
-    0xD2u8 => "定格電圧",
-    0xD3u8 => "瞬時充放電電力計測値",
-    0xD4u8 => "瞬時充放電電流計測値",
-    0xD5u8 => "瞬時充放電電圧計測値",
-    0xD6u8 => "積算放電電力量計測値",
-    0xD7u8 => "積算放電電力量リセット設定",
-    0xD8u8 => "積算充電電力量計測値",
-    0xD9u8 => "積算充電電力量リセット設定",
-    0xDAu8 => "運転モード設定",
-    0xDBu8 => "系統連系状態",
-    0xDCu8 => "充電方式",
-    0xDDu8 => "放電方式",
-    0xDEu8 => "買電電力設定値",
-    0xDFu8 => "再連系許可設定",
-    0xE2u8 => "車載電池の電池残容量1",
-    0xE3u8 => "車載電池の電池残容量2",
-    0xE4u8 => "車載電池の電池残容量3",
-    0xE5u8 => "メンテナンス状態",
-    0xE6u8 => "車両ID",
-    0xE7u8 => "充電量設定値1",
-    0xE9u8 => "充電量設定値2",
-    0xEAu8 => "放電量設定値",
-    0xEBu8 => "充電電力設定値",
-    0xECu8 => "放電電力設定値",
-    0xEDu8 => "充電電流設定値",
-    0xEEu8 => "放電電流設定値",
-    0xEFu8 => "定格電圧（独立時）",
+pub const EVPS_RELEASES: ReleaseRange = ReleaseRange::since(b'D');
+
+pub static HP_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xB0u8 => PropertyDef::new("沸き上げ自動設定", "Automatic water heating setting").access(AccessRule::new(Access::Required, Access::Required, Access::NotApplicable)),
+    0xB1u8 => PropertyDef::new("沸き上げ湯温自動設定", "Automatic water temperature control setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB2u8 => PropertyDef::new("沸き上げ中状態", "Water heater status").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::Required)),
+    0xB3u8 => PropertyDef::new("沸き上げ湯温設定値", "Water heating temperature setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB4u8 => PropertyDef::new("手動沸き上げ停止日数設定値", "Manual water heating stop days setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB5u8 => PropertyDef::new("手動沸き上げOFFタイマ相対時間設定値", "Relative time setting value for manual water heating OFF").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB6u8 => PropertyDef::new("タンク運転モード設定", "Tank operation mode setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC0u8 => PropertyDef::new("昼間沸き増し許可設定", "Daytime reheating permission setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC1u8 => PropertyDef::new("温水器湯温計測値", "Measured temperature of water in water heater").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC2u8 => PropertyDef::new("警報発生状態", "Alarm status").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::Required)),
+    0xC3u8 => PropertyDef::new("給湯中状態", "Hot water supply status").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::Optional)),
+    0xC4u8 => PropertyDef::new("風呂保温運転相対時間設定値", "Relative time setting for keeping bath temperature").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD1u8 => PropertyDef::new("給湯温度設定値", "Temperature of supplied water setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD3u8 => PropertyDef::new("風呂温度設定値", "Bath water temperature setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE0u8 => PropertyDef::new("沸き上げ湯量設定値", "Bath water volume setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE1u8 => PropertyDef::new("残湯量計測値", "Measured amount of water remaining in tank").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE2u8 => PropertyDef::new("タンク容量値", "Tank capacity").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE3u8 => PropertyDef::new("風呂自動モード設定", "Automatic bath water heating mode setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE9u8 => PropertyDef::new("浴室優先設定", "Bathroom priority setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEAu8 => PropertyDef::new("風呂動作状態監視", "Bath operation status monitor").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE4u8 => PropertyDef::new("手動風呂追い焚き動作設定", "Manual bath reheating operation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE5u8 => PropertyDef::new("手動風呂足し湯動作設定", "Manual bath hot water addition function setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE6u8 => PropertyDef::new("手動風呂ぬるめ動作設定", "Manual slight bath water temperature lowering function setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE7u8 => PropertyDef::new("風呂湯量設定1", "Bath water volume setting 1").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE8u8 => PropertyDef::new("風呂湯量設定2", "Bath water volume setting 2").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEEu8 => PropertyDef::new("風呂湯量設定3", "Bath water volume setting 3").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD4u8 => PropertyDef::new("風呂湯量設定4", "Bath water volume setting 4").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD5u8 => PropertyDef::new("風呂湯量設定4設定可能最大レベル", "Bath water volume setting 4 - Maximum settable level").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0x90u8 => PropertyDef::new("ＯＮタイマ予約設定", "ON timer reservation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x91u8 => PropertyDef::new("ＯＮタイマ時刻設定値", "ON timer setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD6u8 => PropertyDef::new("音量設定値", "Volume setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD7u8 => PropertyDef::new("ミュート設定", "Mute setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD8u8 => PropertyDef::new("給湯可能湯量値", "Remaining hot water volume").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD9u8 => PropertyDef::new("余剰電力量予測値", "Surplus electric energy power prediction value").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xDBu8 => PropertyDef::new("冬季H/Pユニット定格消費電力", "Rated power consumption of H/P unit in wintertime").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDCu8 => PropertyDef::new("中間期H/Pユニット定格消費電力", "Rated power consumption of H/P unit in in-between seasons").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDDu8 => PropertyDef::new("夏季H/Pユニット定格消費電力", "Rated power consumption of H/P unit in summertime").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC7u8 => PropertyDef::new("エネルギーシフト参加状態", "Participation in energy shift").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC8u8 => PropertyDef::new("沸き上げ開始基準時刻", "Standard time to start heating").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC9u8 => PropertyDef::new("エネルギーシフト回数", "Number of energy shifts").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCAu8 => PropertyDef::new("昼間沸き上げシフト時刻1", "Daytime heating shift time 1").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xCBu8 => PropertyDef::new("昼間沸き上げシフト時刻1での沸き上げ予測電力量", "Expected electric energy at daytime heating shift time 1").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCCu8 => PropertyDef::new("時間当たり消費電力量1", "Consumption of electric energy per hour 1").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCDu8 => PropertyDef::new("昼間沸き上げシフト時刻2", "Daytime heating shift time 2").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xCEu8 => PropertyDef::new("昼間沸き上げシフト時刻2での沸き上げ予測電力量", "Expected electric energy at daytime heating shift time 2").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCFu8 => PropertyDef::new("時間当たり消費電力量2", "Consumption of electric energy per hour 2").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
 };
 
-pub static HP_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0xB0u8 => "沸き上げ自動設定",
-    0xB1u8 => "沸き上げ湯温自動設定",
-    0xB2u8 => "沸き上げ中状態",
-    0xB3u8 => "沸き上げ湯温設定値",
-    0xB4u8 => "手動沸き上げ停止日数設定値",
-    0xB5u8 => "手動沸き上げOFFタイマ相対時間設定値",
-    0xB6u8 => "タンク運転モード設定",
-    0xC0u8 => "昼間沸き増し許可設定",
-    0xC1u8 => "温水器湯温計測値",
-    0xC2u8 => "警報発生状態",
-    0xC3u8 => "給湯中状態",
-    0xC4u8 => "風呂保温運転相対時間設定値",
-    0xD1u8 => "給湯温度設定値",
-    0xD3u8 => "風呂温度設定値",
-    0xE0u8 => "沸き上げ湯量設定値",
-    0xE1u8 => "残湯量計測値",
-    0xE2u8 => "タンク容量値",
-    0xE3u8 => "風呂自動モード設定",
-    0xE9u8 => "浴室優先設定",
-    0xEAu8 => "風呂動作状態監視",
-    0xE4u8 => "手動風呂追い焚き動作設定",
-    0xE5u8 => "手動風呂足し湯動作設定",
-    0xE6u8 => "手動風呂ぬるめ動作設定",
-    0xE7u8 => "風呂湯量設定1",
-    0xE8u8 => "風呂湯量設定2",
-    0xEEu8 => "風呂湯量設定3",
-    0xD4u8 => "風呂湯量設定4",
-    0xD5u8 => "風呂湯量設定4設定可能最大レベル",
-    0x90u8 => "ＯＮタイマ予約設定",
-    0x91u8 => "ＯＮタイマ時刻設定値",
-    0xD6u8 => "音量設定値",
-    0xD7u8 => "ミュート設定",
-    0xD8u8 => "給湯可能湯量値",
-    0xD9u8 => "余剰電力量予測値",
-    0xDBu8 => "冬季H/Pユニット定格消費電力",
-    0xDCu8 => "中間期H/Pユニット定格消費電力",
-    0xDDu8 => "夏季H/Pユニット定格消費電力",
-    0xC7u8 => "エネルギーシフト参加状態",
-    0xC8u8 => "沸き上げ開始基準時刻",
-    0xC9u8 => "エネルギーシフト回数",
-    0xCAu8 => "昼間沸き上げシフト時刻1",
-    0xCBu8 => "昼間沸き上げシフト時刻1での沸き上げ予測電力量",
-    0xCCu8 => "時間当たり消費電力量1",
-    0xCDu8 => "昼間沸き上げシフト時刻2",
-    0xCEu8 => "昼間沸き上げシフト時刻2での沸き上げ予測電力量",
-    0xCFu8 => "時間当たり消費電力量2",
+pub const HP_RELEASES: ReleaseRange = ReleaseRange::since(b'A');
+
+pub static HOME_AIR_CONDITIONER_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0x80u8 => PropertyDef::new("動作状態", "Operation status").access(AccessRule::new(Access::Required, Access::Required, Access::Required)).states(&ON_OFF),
+    0x8Fu8 => PropertyDef::new("節電動作設定", "Power-saving operation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x90u8 => PropertyDef::new("ONタイマ予約設定", "ON timer reservation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x91u8 => PropertyDef::new("ONタイマ時刻設定値", "ON timer setting (time)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x92u8 => PropertyDef::new("ONタイマ相対時間設定値", "ON timer setting (relative time)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x94u8 => PropertyDef::new("OFFタイマ予約設定", "OFF timer reservation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x95u8 => PropertyDef::new("OFFタイマ時刻設定値", "OFF timer setting (time)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x96u8 => PropertyDef::new("OFFタイマ相対時間設定値", "OFF timer setting (relative time)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xA0u8 => PropertyDef::new("風量設定", "Air flow rate setting").access(AccessRule::new(Access::Required, Access::Required, Access::NotApplicable)),
+    0xA1u8 => PropertyDef::new("風向自動設定", "Automatic control of air flow direction setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xA3u8 => PropertyDef::new("風向スイング設定", "Automatic swing of air flow setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xA4u8 => PropertyDef::new("風向上下設定", "Air flow direction (vertical) setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xA5u8 => PropertyDef::new("風向左右設定", "Air flow direction (horizontal) setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xAAu8 => PropertyDef::new("特殊状態", "Special state").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xABu8 => PropertyDef::new("非優先状態", "Non-priority state").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB0u8 => PropertyDef::new("運転モード設定", "Operation mode setting").access(AccessRule::new(Access::Required, Access::Required, Access::Required)).states(&AC_OPERATION_MODE),
+    0xB1u8 => PropertyDef::new("温度自動設定", "Automatic temperature control setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB2u8 => PropertyDef::new("急速動作モード設定", "Normal/high-speed/silent operation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB3u8 => PropertyDef::new("温度設定値", "Set temperature value").access(AccessRule::new(Access::Required, Access::Required, Access::NotApplicable)).number(NumberDef::unsigned(1, 0, "℃")),
+    0xB4u8 => PropertyDef::new("除湿モード時相対湿度設定値", "Set value of relative humidity in dehumidifying mode").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB5u8 => PropertyDef::new("冷房モード時温度設定値", "Set temperature value in cooling mode").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB6u8 => PropertyDef::new("暖房モード時温度設定値", "Set temperature value in heating mode").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB7u8 => PropertyDef::new("除湿モード時温度設定値", "Set temperature value in dehumidifying mode").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB8u8 => PropertyDef::new("定格消費電力値", "Rated power consumption").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB9u8 => PropertyDef::new("消費電流計測値", "Measured value of current consumption").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xBAu8 => PropertyDef::new("室内相対湿度計測値", "Measured value of room relative humidity").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(1, 0, "%")),
+    0xBBu8 => PropertyDef::new("室内温度計測値", "Measured value of room temperature").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::signed(1, 0, "℃")),
+    0xBCu8 => PropertyDef::new("ユーザリモコン温度設定値", "Set temperature value of user remote control").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xBDu8 => PropertyDef::new("吹き出し温度計測値", "Measured cooled air temperature").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xBEu8 => PropertyDef::new("外気温度計測値", "Measured outdoor air temperature").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::signed(1, 0, "℃")),
+    0xBFu8 => PropertyDef::new("相対温度設定値", "Relative temperature setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC0u8 => PropertyDef::new("換気モード設定", "Ventilation function setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC1u8 => PropertyDef::new("加湿モード設定", "Humidifier function setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC2u8 => PropertyDef::new("換気風量設定", "Ventilation air flow rate setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC4u8 => PropertyDef::new("加湿量設定", "Degree of humidification setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC6u8 => PropertyDef::new("搭載空気清浄方法", "Mounted air cleaning method").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC7u8 => PropertyDef::new("空気清浄機能モード設定", "Air purifier function setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC8u8 => PropertyDef::new("搭載リフレッシュ方法", "Mounted air refresh method").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC9u8 => PropertyDef::new("リフレッシュ機能モード設定", "Air refresher function setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xCAu8 => PropertyDef::new("搭載自己洗浄方法", "Mounted self-cleaning method").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCBu8 => PropertyDef::new("自己洗浄機能モード設定", "Self-cleaning function setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xCCu8 => PropertyDef::new("特別運転モード設定", "Special function setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xCDu8 => PropertyDef::new("内部動作状態", "Operation status of components").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCEu8 => PropertyDef::new("強制サーモモード設定", "Thermostat setting override function").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xCFu8 => PropertyDef::new("空気清浄モード設定", "Air purification mode setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD0u8 => PropertyDef::new("ブザー", "Buzzer").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
 };
 
-pub static HOME_AIR_CONDITIONER_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0x80u8 => "動作状態",
-    0x8Fu8 => "節電動作設定",
-    0x90u8 => "ONタイマ予約設定",
-    0x91u8 => "ONタイマ時刻設定値",
-    0x92u8 => "ONタイマ相対時間設定値",
-    0x94u8 => "OFFタイマ予約設定",
-    0x95u8 => "OFFタイマ時刻設定値",
-    0x96u8 => "OFFタイマ相対時間設定値",
-    0xA0u8 => "風量設定",
-    0xA1u8 => "風向自動設定",
-    0xA3u8 => "風向スイング設定",
-    0xA4u8 => "風向上下設定",
-    0xA5u8 => "風向左右設定",
-    0xAAu8 => "特殊状態",
-    0xABu8 => "非優先状態",
-    0xB0u8 => "運転モード設定",
-    0xB1u8 => "温度自動設定",
-    0xB2u8 => "急速動作モード設定",
-    0xB3u8 => "温度設定値",
-    0xB4u8 => "除湿モード時相対湿度設定値",
-    0xB5u8 => "冷房モード時温度設定値",
-    0xB6u8 => "暖房モード時温度設定値",
-    0xB7u8 => "除湿モード時温度設定値",
-    0xB8u8 => "定格消費電力値",
-    0xB9u8 => "消費電流計測値",
-    0xBAu8 => "室内相対湿度計測値",
-    0xBBu8 => "室内温度計測値",
-    0xBCu8 => "ユーザリモコン温度設定値",
-    0xBDu8 => "吹き出し温度計測値",
-    0xBEu8 => "外気温度計測値",
-    0xBFu8 => "相対温度設定値",
-    0xC0u8 => "換気モード設定",
-    0xC1u8 => "加湿モード設定",
-    0xC2u8 => "換気風量設定",
-    0xC4u8 => "加湿量設定",
-    0xC6u8 => "搭載空気清浄方法",
-    0xC7u8 => "空気清浄機能モード設定",
-    0xC8u8 => "搭載リフレッシュ方法",
-    0xC9u8 => "リフレッシュ機能モード設定",
-    0xCAu8 => "搭載自己洗浄方法",
-    0xCBu8 => "自己洗浄機能モード設定",
-    0xCCu8 => "特別運転モード設定",
-    0xCDu8 => "内部動作状態",
-    0xCEu8 => "強制サーモモード設定",
-    0xCFu8 => "空気清浄モード設定",
-    0xD0u8 => "ブザー",
+pub const HOME_AIR_CONDITIONER_RELEASES: ReleaseRange = ReleaseRange::since(b'A');
+
+pub static POWER_DISTRIBUTION_BOARD_METERING_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xB0u8 => PropertyDef::new("主幹定格容量", "Master rated capacity").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB1u8 => PropertyDef::new("計測チャンネル数 (片方向)", "Number of measurement channels (simplex)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB2u8 => PropertyDef::new("積算電力量計測チャンネル範囲指定 (片方向)", "Channel range specification for cumulative amount of electric power consumption measurement (simplex)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB3u8 => PropertyDef::new("積算電力量計測値リスト (片方向)", "Measured cumulative amount of electric power consumption list (simplex)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB4u8 => PropertyDef::new("瞬時電流計測チャンネル範囲指定 (片方向)", "Channel range specification for instantaneous current measurement (simplex)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB5u8 => PropertyDef::new("瞬時電流計測値リスト (片方向)", "Measured instantaneous current list (simplex)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB6u8 => PropertyDef::new("瞬時電力計測チャンネル範囲指定 (片方向)", "Channel range specification for instantaneous power consumption measurement (simplex)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB7u8 => PropertyDef::new("瞬時電力計測値リスト (片方向)", "Measured instantaneous power consumption list (simplex)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB8u8 => PropertyDef::new("計測チャンネル数 (双方向)", "Number of measurement channels (duplex)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB9u8 => PropertyDef::new("積算電力量計測チャンネル範囲指定 (双方向)", "Channel range specification for cumulative amount of electric power consumption measurement (duplex)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xBAu8 => PropertyDef::new("積算電力量計測値リスト (双方向)", "Measured cumulative amount of electric power consumption list (duplex)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xBBu8 => PropertyDef::new("瞬時電流計測チャンネル範囲指定 (双方向)", "Channel range specification for instantaneous current measurement (duplex)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xBCu8 => PropertyDef::new("瞬時電流計測値リスト (双方向)", "Measured instantaneous current list (duplex)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xBDu8 => PropertyDef::new("瞬時電力計測チャンネル範囲指定 (双方向)", "Channel range specification for instantaneous power consumption measurement (duplex)").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xBEu8 => PropertyDef::new("瞬時電力計測値リスト (双方向)", "Measured instantaneous power consumption list (duplex)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC0u8 => PropertyDef::new("積算電力量計測値 (正方向)", "Measured cumulative amount of electric energy (normal direction)").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xC1u8 => PropertyDef::new("積算電力量計測値 (逆方向)", "Measured cumulative amount of electric energy (reverse direction)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC2u8 => PropertyDef::new("積算電力量単位", "Unit for cumulative amounts of electric energy").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xC3u8 => PropertyDef::new("積算電力量計測値履歴 (正方向)", "Historical data of measured cumulative amounts of electric energy (normal direction)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC4u8 => PropertyDef::new("積算電力量計測値履歴 (逆方向)", "Historical data of measured cumulative amounts of electric energy (reverse direction)").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC5u8 => PropertyDef::new("積算履歴収集日", "Day for which the historical data of measured cumulative amounts of electric energy is to be retrieved").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC6u8 => PropertyDef::new("瞬時電力計測値", "Measured instantaneous amount of electric energy").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC7u8 => PropertyDef::new("瞬時電流計測値", "Measured instantaneous currents").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC8u8 => PropertyDef::new("瞬時電圧計測値", "Measured instantaneous voltages").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD0u8 => PropertyDef::new("計測チャンネル1", "Measurement channel 1").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD1u8 => PropertyDef::new("計測チャンネル2", "Measurement channel 2").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD2u8 => PropertyDef::new("計測チャンネル3", "Measurement channel 3").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD3u8 => PropertyDef::new("計測チャンネル4", "Measurement channel 4").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD4u8 => PropertyDef::new("計測チャンネル5", "Measurement channel 5").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD5u8 => PropertyDef::new("計測チャンネル6", "Measurement channel 6").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD6u8 => PropertyDef::new("計測チャンネル7", "Measurement channel 7").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD7u8 => PropertyDef::new("計測チャンネル8", "Measurement channel 8").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD8u8 => PropertyDef::new("計測チャンネル9", "Measurement channel 9").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD9u8 => PropertyDef::new("計測チャンネル10", "Measurement channel 10").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDAu8 => PropertyDef::new("計測チャンネル11", "Measurement channel 11").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDBu8 => PropertyDef::new("計測チャンネル12", "Measurement channel 12").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDCu8 => PropertyDef::new("計測チャンネル13", "Measurement channel 13").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDDu8 => PropertyDef::new("計測チャンネル14", "Measurement channel 14").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDEu8 => PropertyDef::new("計測チャンネル15", "Measurement channel 15").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xDFu8 => PropertyDef::new("計測チャンネル16", "Measurement channel 16").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE0u8 => PropertyDef::new("計測チャンネル17", "Measurement channel 17").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE1u8 => PropertyDef::new("計測チャンネル18", "Measurement channel 18").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE2u8 => PropertyDef::new("計測チャンネル19", "Measurement channel 19").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE3u8 => PropertyDef::new("計測チャンネル20", "Measurement channel 20").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE4u8 => PropertyDef::new("計測チャンネル21", "Measurement channel 21").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE5u8 => PropertyDef::new("計測チャンネル22", "Measurement channel 22").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE6u8 => PropertyDef::new("計測チャンネル23", "Measurement channel 23").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE7u8 => PropertyDef::new("計測チャンネル24", "Measurement channel 24").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE8u8 => PropertyDef::new("計測チャンネル25", "Measurement channel 25").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE9u8 => PropertyDef::new("計測チャンネル26", "Measurement channel 26").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xEAu8 => PropertyDef::new("計測チャンネル27", "Measurement channel 27").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xEBu8 => PropertyDef::new("計測チャンネル28", "Measurement channel 28").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xECu8 => PropertyDef::new("計測チャンネル29", "Measurement channel 29").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xEDu8 => PropertyDef::new("計測チャンネル30", "Measurement channel 30").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xEEu8 => PropertyDef::new("計測チャンネル31", "Measurement channel 31").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xEFu8 => PropertyDef::new("計測チャンネル32", "Measurement channel 32").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
 };
 
-pub static POWER_DISTRIBUTION_BOARD_METERING_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0xB0u8 => "主幹定格容量",
-    0xB1u8 => "計測チャンネル数 (片方向)",
-    0xB2u8 => "積算電力量計測チャンネル範囲指定 (片方向)",
-    0xB3u8 => "積算電力量計測値リスト (片方向)",
-    0xB4u8 => "瞬時電流計測チャンネル範囲指定 (片方向)",
-    0xB5u8 => "瞬時電流計測値リスト (片方向)",
-    0xB6u8 => "瞬時電力計測チャンネル範囲指定 (片方向)",
-    0xB7u8 => "瞬時電力計測値リスト (片方向)",
-    0xB8u8 => "計測チャンネル数 (双方向)",
-    0xB9u8 => "積算電力量計測チャンネル範囲指定 (双方向)",
-    0xBAu8 => "積算電力量計測値リスト (双方向)",
-    0xBBu8 => "瞬時電流計測チャンネル範囲指定 (双方向)",
-    0xBCu8 => "瞬時電流計測値リスト (双方向)",
-    0xBDu8 => "瞬時電力計測チャンネル範囲指定 (双方向)",
-    0xBEu8 => "瞬時電力計測値リスト (双方向)",
-    0xC0u8 => "積算電力量計測値 (正方向)",
-    0xC1u8 => "積算電力量計測値 (逆方向)",
-    0xC2u8 => "積算電力量単位",
-    0xC3u8 => "積算電力量計測値履歴 (正方向)",
-    0xC4u8 => "積算電力量計測値履歴 (逆方向)",
-    0xC5u8 => "積算履歴収集日",
-    0xC6u8 => "瞬時電力計測値",
-    0xC7u8 => "瞬時電流計測値",
-    0xC8u8 => "瞬時電圧計測値",
-    0xD0u8 => "計測チャンネル1",
-    0xD1u8 => "計測チャンネル2",
-    0xD2u8 => "計測チャンネル3",
-    0xD3u8 => "計測チャンネル4",
-    0xD4u8 => "計測チャンネル5",
-    0xD5u8 => "計測チャンネル6",
-    0xD6u8 => "計測チャンネル7",
-    0xD7u8 => "計測チャンネル8",
-    0xD8u8 => "計測チャンネル9",
-    0xD9u8 => "計測チャンネル10",
-    0xDAu8 => "計測チャンネル11",
-    0xDBu8 => "計測チャンネル12",
-    0xDCu8 => "計測チャンネル13",
-    0xDDu8 => "計測チャンネル14",
-    0xDEu8 => "計測チャンネル15",
-    0xDFu8 => "計測チャンネル16",
-    0xE0u8 => "計測チャンネル17",
-    0xE1u8 => "計測チャンネル18",
-    0xE2u8 => "計測チャンネル19",
-    0xE3u8 => "計測チャンネル20",
-    0xE4u8 => "計測チャンネル21",
-    0xE5u8 => "計測チャンネル22",
-    0xE6u8 => "計測チャンネル23",
-    0xE7u8 => "計測チャンネル24",
-    0xE8u8 => "計測チャンネル25",
-    0xE9u8 => "計測チャンネル26",
-    0xEAu8 => "計測チャンネル27",
-    0xEBu8 => "計測チャンネル28",
-    0xECu8 => "計測チャンネル29",
-    0xEDu8 => "計測チャンネル30",
-    0xEEu8 => "計測チャンネル31",
-    0xEFu8 => "計測チャンネル32",
+pub const POWER_DISTRIBUTION_BOARD_METERING_RELEASES: ReleaseRange = ReleaseRange::since(b'B');
+
+pub static FUEL_CELL_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xC1u8 => PropertyDef::new("温水器湯温計測値", "Measured temperature of water in water heater").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC2u8 => PropertyDef::new("定格発電量", "Rated power generation output").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC3u8 => PropertyDef::new("貯湯槽熱量", "Heating value of hot water storage tank").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC4u8 => PropertyDef::new("瞬時発電電力計測値", "Measured instantaneous power generation output").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(2, 0, "W")),
+    0xC5u8 => PropertyDef::new("積算発電電力量計測値", "Measured cumulative power generation output").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xC6u8 => PropertyDef::new("積算発電電力量リセット設定", "Cumulative power generation output reset setting").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
+    0xC7u8 => PropertyDef::new("瞬時ガス消費量計測値", "Measured instantaneous gas consumption").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC8u8 => PropertyDef::new("積算ガス消費量計測値", "Measured cumulative gas consumption").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC9u8 => PropertyDef::new("積算ガス消費量リセット設定", "Cumulative gas consumption reset setting").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
+    0xCAu8 => PropertyDef::new("発電動作設定", "Power generation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xCBu8 => PropertyDef::new("発電動作状態", "Power generation status").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::Required)),
+    0xCCu8 => PropertyDef::new("宅内瞬時消費電力計測値", "Measured in-house instantaneous power consumption").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCDu8 => PropertyDef::new("宅内積算消費電力量計測値", "Measured in-house cumulative power consumption").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCEu8 => PropertyDef::new("宅内積算消費電力量リセット設定", "In-house cumulative power consumption reset").access(AccessRule::new(Access::NotApplicable, Access::Optional, Access::NotApplicable)),
+    0xD0u8 => PropertyDef::new("系統連系状態", "System interconnected type").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xD1u8 => PropertyDef::new("発電要請時刻設定", "Power generation request time setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD2u8 => PropertyDef::new("指定発電状態", "Designated power generation status").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE1u8 => PropertyDef::new("残湯量計測値", "Measured remaining hot water amount").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE2u8 => PropertyDef::new("タンク容量値", "Tank capacity").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
 };
 
-pub static FUEL_CELL_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0xC1u8 => "温水器湯温計測値",
-    0xC2u8 => "定格発電量",
-    0xC3u8 => "貯湯槽熱量",
-    0xC4u8 => "瞬時発電電力計測値",
-    0xC5u8 => "積算発電電力量計測値",
-    0xC6u8 => "積算発電電力量リセット設定",
-    0xC7u8 => "瞬時ガス消費量計測値",
-    0xC8u8 => "積算ガス消費量計測値",
-    0xC9u8 => "積算ガス消費量リセット設定",
-    0xCAu8 => "発電動作設定",
-    0xCBu8 => "発電動作状態",
-    0xCCu8 => "宅内瞬時消費電力計測値",
-    0xCDu8 => "宅内積算消費電力量計測値",
-    0xCEu8 => "宅内積算消費電力量リセット設定",
-    0xD0u8 => "系統連系状態",
-    0xD1u8 => "発電要請時刻設定",
-    0xD2u8 => "指定発電状態",
-    0xE1u8 => "残湯量計測値",
-    0xE2u8 => "タンク容量値",
+pub const FUEL_CELL_RELEASES: ReleaseRange = ReleaseRange::since(b'A');
+
+pub static INSTANTANEOUS_WATER_HEATER_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0x90u8 => PropertyDef::new("風呂自動ONタイマ予約設定", "Bath auto ON timer reservation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x91u8 => PropertyDef::new("ONタイマ時刻設定値", "ON timer setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x92u8 => PropertyDef::new("風呂自動ONタイマ相対時間設定値", "Bath auto ON timer relative time setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD0u8 => PropertyDef::new("給湯器燃焼状態", "Hot water heating status").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::Required)),
+    0xD1u8 => PropertyDef::new("給湯温度設定値", "Set value of hot water temperature").access(AccessRule::new(Access::Required, Access::Required, Access::NotApplicable)),
+    0xD2u8 => PropertyDef::new("給湯保温設定", "Hot water warmer setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD4u8 => PropertyDef::new("風呂湯量設定4", "Bath water volume setting 4").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD5u8 => PropertyDef::new("風呂湯量設定4 設定可能最大レベル", "Bath water volume setting 4 - Maximum settable level").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xD6u8 => PropertyDef::new("音量設定値", "Volume setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xD7u8 => PropertyDef::new("ミュート設定", "Mute setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xDAu8 => PropertyDef::new("自動運転時間設定値", "Duration of automatic operation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xDBu8 => PropertyDef::new("自動運転残時間", "Remaining automatic operation time").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE1u8 => PropertyDef::new("風呂温度設定値", "Set value of bath temperature").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE2u8 => PropertyDef::new("風呂給湯器燃焼状態", "Bath water heater status").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::Optional)),
+    0xE3u8 => PropertyDef::new("風呂自動モード設定", "Bath auto mode setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE4u8 => PropertyDef::new("風呂追い炊き動作設定", "Bath additional boil-up operation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE5u8 => PropertyDef::new("風呂足し湯動作設定", "Bath hot water adding operation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE6u8 => PropertyDef::new("風呂ぬるめ動作設定", "Bath water temperature lowering operation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE7u8 => PropertyDef::new("風呂湯量設定1", "Bath hot water volume setting 1").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xE8u8 => PropertyDef::new("風呂湯量設定2", "Bath hot water volume setting 2").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE9u8 => PropertyDef::new("浴室優先設定", "Bathroom priority setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEAu8 => PropertyDef::new("シャワー給湯状態", "Shower hot water supply status").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::Optional)),
+    0xEBu8 => PropertyDef::new("台所給湯状態", "Kitchen hot water supply status").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::Optional)),
+    0xECu8 => PropertyDef::new("給湯保温ONタイマ予約設定", "Hot water warmer ON timer reservation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEDu8 => PropertyDef::new("給湯保温ONタイマ時刻設定値", "Set value of hot water warmer ON timer time").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEEu8 => PropertyDef::new("風呂湯量設定3", "Bath hot water volume setting 3").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xEFu8 => PropertyDef::new("風呂動作状態監視", "Bath operation status monitor").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
 };
 
-pub static INSTANTANEOUS_WATER_HEATER_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0x90u8 => "風呂自動ONタイマ予約設定",
-    0x91u8 => "ONタイマ時刻設定値",
-    0x92u8 => "風呂自動ONタイマ相対時間設定値",
-    0xD0u8 => "給湯器燃焼状態",
-    0xD1u8 => "給湯温度設定値",
-    0xD2u8 => "給湯保温設定",
-    0xD4u8 => "風呂湯量設定4",
-    0xD5u8 => "風呂湯量設定4 設定可能最大レベル",
-    0xD6u8 => "音量設定値",
-    0xD7u8 => "ミュート設定",
-    0xDAu8 => "自動運転時間設定値",
-    0xDBu8 => "自動運転残時間",
-    0xE1u8 => "風呂温度設定値",
-    0xE2u8 => "風呂給湯器燃焼状態",
-    0xE3u8 => "風呂自動モード設定",
-    0xE4u8 => "風呂追い炊き動作設定",
-    0xE5u8 => "風呂足し湯動作設定",
-    0xE6u8 => "風呂ぬるめ動作設定",
-    0xE7u8 => "風呂湯量設定1",
-    0xE8u8 => "風呂湯量設定2",
-    0xE9u8 => "浴室優先設定",
-    0xEAu8 => "シャワー給湯状態",
-    0xEBu8 => "台所給湯状態",
-    0xECu8 => "給湯保温ONタイマ予約設定",
-    0xEDu8 => "給湯保温ONタイマ時刻設定値",
-    0xEEu8 => "風呂湯量設定3",
-    0xEFu8 => "風呂動作状態監視",
+pub const INSTANTANEOUS_WATER_HEATER_RELEASES: ReleaseRange = ReleaseRange::since(b'A');
+
+pub static GENERAL_LIGHTING_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0x80u8 => PropertyDef::new("動作状態", "Operation status").access(AccessRule::new(Access::Required, Access::Required, Access::Required)).states(&ON_OFF),
+    0x90u8 => PropertyDef::new("ONタイマ予約設定", "ON timer reservation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x91u8 => PropertyDef::new("ONタイマ時刻設定値", "ON timer setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x94u8 => PropertyDef::new("OFFタイマ予約設定", "OFF timer reservation setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0x95u8 => PropertyDef::new("OFFタイマ時刻設定値", "OFF timer setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB0u8 => PropertyDef::new("照明の明るさ設定", "Illuminance level").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)).number(NumberDef::unsigned(1, 0, "%")),
+    0xB1u8 => PropertyDef::new("光色設定", "Light color setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB2u8 => PropertyDef::new("照明の明るさ段数設定", "Illuminance level step setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB3u8 => PropertyDef::new("光色レベル段数設定", "Light color step setting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB4u8 => PropertyDef::new("設定可能レベル最大値", "Maximum specifiable values").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB5u8 => PropertyDef::new("常夜灯設定可能レベル最大値", "Maximum value of settable level for night lighting").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xB6u8 => PropertyDef::new("点灯モード設定", "Lighting mode setting").access(AccessRule::new(Access::Required, Access::Required, Access::NotApplicable)),
+    0xB7u8 => PropertyDef::new("通常灯モード時照明の明るさ設定", "Illuminance level setting for main lighting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB8u8 => PropertyDef::new("通常灯モード時照明の明るさ段数設定", "Illuminance level step setting for main lighting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xB9u8 => PropertyDef::new("常夜灯モード時照明の明るさ設定", "Illuminance level setting for night lighting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xBAu8 => PropertyDef::new("常夜灯モード時照明の明るさ段数設定", "Illuminance level step setting for night lighting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xBBu8 => PropertyDef::new("通常灯モード時光色設定", "Light color setting for main lighting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xBCu8 => PropertyDef::new("通常灯モード時光色レベル段数設定", "Light color level step setting for main lighting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xBDu8 => PropertyDef::new("常夜灯モード時光色設定", "Light color setting for night lighting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xBEu8 => PropertyDef::new("常夜灯モード時光色レベル段数設定", "Light color level step setting for night lighting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xBFu8 => PropertyDef::new("自動モード時点灯モード状態", "Lighting mode status in auto mode").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC0u8 => PropertyDef::new("カラー灯モード時RGB設定", "RGB setting for color lighting").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
 };
 
-pub static GENERAL_LIGHTING_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0x80u8 => "動作状態",
-    0x90u8 => "ONタイマ予約設定",
-    0x91u8 => "ONタイマ時刻設定値",
-    0x94u8 => "OFFタイマ予約設定",
-    0x95u8 => "OFFタイマ時刻設定値",
-    0xB0u8 => "照明の明るさ設定",
-    0xB1u8 => "光色設定",
-    0xB2u8 => "照明の明るさ段数設定",
-    0xB3u8 => "光色レベル段数設定",
-    0xB4u8 => "設定可能レベル最大値",
-    0xB5u8 => "常夜灯設定可能レベル最大値",
-    0xB6u8 => "点灯モード設定",
-    0xB7u8 => "通常灯モード時照明の明るさ設定",
-    0xB8u8 => "通常灯モード時照明の明るさ段数設定",
-    0xB9u8 => "常夜灯モード時照明の明るさ設定",
-    0xBAu8 => "常夜灯モード時照明の明るさ段数設定",
-    0xBBu8 => "通常灯モード時光色設定",
-    0xBCu8 => "通常灯モード時光色レベル段数設定",
-    0xBDu8 => "常夜灯モード時光色設定",
-    0xBEu8 => "常夜灯モード時光色レベル段数設定",
-    0xBFu8 => "自動モード時点灯モード状態",
-    0xC0u8 => "カラー灯モード時RGB設定",
+pub const GENERAL_LIGHTING_RELEASES: ReleaseRange = ReleaseRange::since(b'A');
+
+pub static MONO_FUNCTION_LIGHTING_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0x80u8 => PropertyDef::new("動作状態", "Operation status").access(AccessRule::new(Access::Required, Access::Required, Access::Required)).states(&ON_OFF),
+    0xB0u8 => PropertyDef::new("照明の明るさ設定", "Illuminance level").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)).number(NumberDef::unsigned(1, 0, "%")),
 };
 
-pub static MONO_FUNCTION_LIGHTING_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0x80u8 => "動作状態",
-    0xB0u8 => "照明の明るさ設定",
+pub const MONO_FUNCTION_LIGHTING_RELEASES: ReleaseRange = ReleaseRange::since(b'F');
+
+pub static LIGHTING_SYSTEM_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xB0u8 => PropertyDef::new("照明の明るさ設定", "Illuminance level").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC0u8 => PropertyDef::new("シーン制御設定", "Scene control setting").access(AccessRule::new(Access::Required, Access::Required, Access::NotApplicable)),
+    0xC1u8 => PropertyDef::new("シーン制御設定可能数", "Number that can assign scene control setting").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
 };
 
-pub static LIGHTING_SYSTEM_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0xB0u8 => "照明の明るさ設定",
-    0xC0u8 => "シーン制御設定",
-    0xC1u8 => "シーン制御設定可能数",
+pub const LIGHTING_SYSTEM_RELEASES: ReleaseRange = ReleaseRange::since(b'F');
+
+pub static MULTI_INPUT_PCS_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xD0u8 => PropertyDef::new("系統連系状態", "System-interconnected type").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+    0xE0u8 => PropertyDef::new("積算電力量計測値（正方向）", "Measured cumulative amount of electric energy (normal direction)").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(4, -3, "kWh")),
+    0xE3u8 => PropertyDef::new("積算電力量計測値（逆方向）", "Measured cumulative amount of electric energy (reverse direction)").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::unsigned(4, -3, "kWh")),
+    0xE7u8 => PropertyDef::new("瞬時電力計測値", "Measured instantaneous amount of electricity").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)).number(NumberDef::signed(4, 0, "W")),
+    0xE8u8 => PropertyDef::new("接続機器", "Connected devices").access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
 };
 
-pub static CONTROLLER_CLASS: phf::Map<u8, &'static str> = phf_map! {
-    0xC0u8 => "コントローラID",
-    0xC1u8 => "管理台数",
-    0xC2u8 => "インデックス",
-    0xC3u8 => "機器ID",
-    0xC4u8 => "機種",
-    0xC5u8 => "名称",
-    0xC6u8 => "接続状態",
-    0xC7u8 => "管理対象機器事業者コード",
-    0xC8u8 => "管理対象機器商品コード",
-    0xC9u8 => "管理対象機器製造年月日",
-    0xCAu8 => "管理対象機器登録情報更新年月日",
-    0xCBu8 => "管理対象機器登録情報更新バージョン情報",
-    0xCCu8 => "管理対象機器設置場所",
-    0xCDu8 => "管理対象機器異常発生状態",
-    0xCEu8 => "管理対象機器Setプロパティマップ",
-    0xCFu8 => "管理対象機器Getプロパティマップ",
-    0xE0u8 => "設置住所",
+pub const MULTI_INPUT_PCS_RELEASES: ReleaseRange = ReleaseRange::since(b'J');
+
+pub static CONTROLLER_CLASS: phf::Map<u8, PropertyDef> = phf_map! {
+    0xC0u8 => PropertyDef::new("コントローラID", "Controller ID").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC1u8 => PropertyDef::new("管理台数", "Number of devices controlled").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC2u8 => PropertyDef::new("インデックス", "Index").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
+    0xC3u8 => PropertyDef::new("機器ID", "Device ID").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC4u8 => PropertyDef::new("機種", "Device type").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC5u8 => PropertyDef::new("名称", "Name").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC6u8 => PropertyDef::new("接続状態", "Connection status").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC7u8 => PropertyDef::new("管理対象機器事業者コード", "Business code of the device to be controlled").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC8u8 => PropertyDef::new("管理対象機器商品コード", "Product code of the device to be controlled").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xC9u8 => PropertyDef::new("管理対象機器製造年月日", "Manufacture date of the device to be controlled").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCAu8 => PropertyDef::new("管理対象機器登録情報更新年月日", "Registration information update date of the device to be controlled").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCBu8 => PropertyDef::new("管理対象機器登録情報更新バージョン情報", "Registration information update version information of the device to be controlled").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCCu8 => PropertyDef::new("管理対象機器設置場所", "Installation location of the device to be controlled").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCDu8 => PropertyDef::new("管理対象機器異常発生状態", "Fault status of the device to be controlled").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCEu8 => PropertyDef::new("管理対象機器Setプロパティマップ", "Set property map of the device to be controlled").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xCFu8 => PropertyDef::new("管理対象機器Getプロパティマップ", "Get property map of the device to be controlled").access(AccessRule::new(Access::Optional, Access::NotApplicable, Access::NotApplicable)),
+    0xE0u8 => PropertyDef::new("設置住所", "Installation address").access(AccessRule::new(Access::Optional, Access::Optional, Access::NotApplicable)),
 };
+
+pub const CONTROLLER_RELEASES: ReleaseRange = ReleaseRange::since(b'A');
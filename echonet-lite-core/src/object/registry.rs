@@ -0,0 +1,518 @@
+use super::{
+    ClassCode, ClassPacket, EchonetObject, LocalizedName, PropertyDef, PropertyMap, Release,
+    ReleaseRange, UnimplementedPacket, BUILTIN_CLASSES, SUPER_CLASS,
+};
+use crate::error::{Error, ErrorKind};
+use crate::lib::Vec;
+use crate::{Edt, ElPacket, Properties, Property, ServiceCode};
+
+/// Definition of an ECHONET Lite class.
+///
+/// The built-in classes are listed in [`BUILTIN_CLASSES`].
+/// Downstream crates can define their own classes, e.g. manufacturer-private ones,
+/// and register them to a [`ClassRegistry`].
+///
+/// # Examples
+///
+/// ```
+/// use echonet_lite::object::{
+///     ClassDef, ClassPacket, ClassRegistry, LocalizedName, PropertyDef, PropertyMap, ReleaseRange,
+/// };
+/// use echonet_lite::{prelude::*, props};
+///
+/// static PRIVATE_CLASS_PROPS: phf::Map<u8, PropertyDef> = phf::phf_map! {
+///     0xF0u8 => PropertyDef::new("独自プロパティ", "Private property"),
+/// };
+///
+/// static PRIVATE_CLASS: ClassDef = ClassDef {
+///     code: [0x02, 0xF0],
+///     name: LocalizedName::new("独自クラス", "Private Class"),
+///     short_name: "Private class",
+///     properties: PropertyMap::Static(&PRIVATE_CLASS_PROPS),
+///     releases: ReleaseRange::ALL,
+///     decode: ClassPacket::custom,
+/// };
+///
+/// let mut registry = ClassRegistry::default();
+/// registry.register(&PRIVATE_CLASS);
+///
+/// let packet = registry.decode([0x02, 0xF0, 0x01].into(), props!([0xF0, [0x01]]));
+/// assert!(matches!(packet, ClassPacket::Custom(_)));
+/// ```
+#[derive(Debug)]
+pub struct ClassDef {
+    /// Class group code and class code.
+    pub code: [u8; 2],
+    /// Name used in the header of packet displays.
+    pub name: LocalizedName,
+    /// Name used in ECHONET object displays.
+    pub short_name: &'static str,
+    /// Definitions of the class specific properties.
+    pub properties: PropertyMap,
+    /// Appendix releases defining the class.
+    pub releases: ReleaseRange,
+    /// Creates the class specific packet from properties.
+    pub decode: fn(&'static ClassDef, Properties) -> ClassPacket,
+}
+
+impl ClassDef {
+    /// Finds the built-in class definition for `code`.
+    pub fn builtin(code: [u8; 2]) -> Option<&'static ClassDef> {
+        BUILTIN_CLASSES.iter().find(|class| class.code == code)
+    }
+
+    /// Finds the definition of `epc`, including the super class properties.
+    pub fn property(&self, epc: u8) -> Option<&PropertyDef> {
+        self.properties.get(epc).or_else(|| SUPER_CLASS.get(&epc))
+    }
+
+    /// Returns true if `epc` is defined for this class at `release`.
+    pub fn is_valid_epc(&self, epc: u8, release: Release) -> bool {
+        self.releases.contains(release)
+            && self
+                .property(epc)
+                .is_some_and(|prop| prop.releases.contains(release))
+    }
+}
+
+/// A set of class definitions used to interpret ECHONET Lite packets.
+///
+/// The default registry contains all the [`BUILTIN_CLASSES`].
+#[derive(Debug, Clone)]
+pub struct ClassRegistry {
+    classes: Vec<&'static ClassDef>,
+}
+
+impl Default for ClassRegistry {
+    fn default() -> Self {
+        Self {
+            classes: BUILTIN_CLASSES.iter().collect(),
+        }
+    }
+}
+
+impl ClassRegistry {
+    /// Creates an empty registry.
+    pub fn empty() -> Self {
+        Self {
+            classes: Vec::new(),
+        }
+    }
+
+    /// Registers a class definition.
+    ///
+    /// A definition already registered for the same class code is replaced.
+    pub fn register(&mut self, class: &'static ClassDef) -> &mut Self {
+        match self.classes.iter_mut().find(|c| c.code == class.code) {
+            Some(registered) => *registered = class,
+            None => self.classes.push(class),
+        }
+        self
+    }
+
+    /// Finds the class definition for `code`.
+    pub fn get(&self, code: [u8; 2]) -> Option<&'static ClassDef> {
+        self.classes
+            .iter()
+            .copied()
+            .find(|class| class.code == code)
+    }
+
+    /// Iterates over all the registered class definitions.
+    pub fn iter(&self) -> impl Iterator<Item = &'static ClassDef> + '_ {
+        self.classes.iter().copied()
+    }
+
+    /// Returns true if `epc` is defined for `class` at `release`.
+    ///
+    /// Returns `None` if `class` is not registered.
+    pub fn is_valid_epc(&self, class: ClassCode, epc: u8, release: Release) -> Option<bool> {
+        self.get(class.0)
+            .map(|class| class.is_valid_epc(epc, release))
+    }
+
+    /// Returns true if `esv` may be accepted for `epc` of `class`.
+    ///
+    /// Properties of unregistered classes, properties without a definition and
+    /// user-defined properties (0xF0 to 0xFF) are always allowed.
+    pub fn is_allowed(&self, class: ClassCode, esv: ServiceCode, epc: u8) -> bool {
+        match self.get(class.0) {
+            Some(class) if epc < 0xF0 => class
+                .property(epc)
+                .is_none_or(|prop| prop.access.allows(esv)),
+            _ => true,
+        }
+    }
+
+    /// Validates a request against the access rules of the destination class,
+    /// e.g. before sending a SetC.
+    ///
+    /// SetGet is validated by the Set rule for the properties to write
+    /// and by the Get rule for the properties to read.
+    pub fn validate_request(&self, request: &ElPacket) -> Result<(), Error> {
+        let class = request.deoj.class;
+        let denied = access_lists(request).into_iter().find_map(|(esv, props)| {
+            props
+                .iter()
+                .find(|prop| !self.is_allowed(class, esv, prop.epc))
+        });
+        match denied {
+            Some(prop) => Err(ErrorKind::AccessDenied {
+                class: class.0,
+                epc: prop.epc,
+            }
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Creates the "response not possible" (SNA) response for a request
+    /// accessing properties in the ways their access rules do not allow.
+    ///
+    /// `process` is called for each allowed property with the service it is accessed by,
+    /// i.e. SetC for the properties written by SetGet and Get for those read by SetGet.
+    /// It returns the response property, i.e. the property with empty EDT if written,
+    /// or with its value if read. The disallowed properties are answered as not processed.
+    ///
+    /// Returns `None` if all the properties are allowed,
+    /// or if `request` is not SetI, SetC, Get, InfReq or SetGet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use echonet_lite::object::{
+    ///     Access, AccessRule, ClassDef, ClassRegistry, LocalizedName, PropertyDef, PropertyMap,
+    ///     ReleaseRange,
+    /// };
+    /// use echonet_lite::{prelude::*, props};
+    ///
+    /// static PROPS: phf::Map<u8, PropertyDef> = phf::phf_map! {
+    ///     0xE0u8 => PropertyDef::new("測定値", "Measured value")
+    ///         .access(AccessRule::new(Access::Required, Access::NotApplicable, Access::Optional)),
+    /// };
+    /// static SENSOR: ClassDef = ClassDef {
+    ///     code: [0x00, 0xF0],
+    ///     name: LocalizedName::new("センサ", "Sensor"),
+    ///     short_name: "Sensor",
+    ///     properties: PropertyMap::Static(&PROPS),
+    ///     releases: ReleaseRange::ALL,
+    ///     decode: ClassPacket::custom,
+    /// };
+    ///
+    /// let mut registry = ClassRegistry::empty();
+    /// registry.register(&SENSOR);
+    ///
+    /// let request = ElPacketBuilder::new()
+    ///     .seoj([0x05, 0xFF, 0x01])
+    ///     .deoj([0x00, 0xF0, 0x01])
+    ///     .esv(ServiceCode::SetC)
+    ///     .props(props!([0xE0, [0x01]]))
+    ///     .build();
+    /// assert!(registry.validate_request(&request).is_err());
+    ///
+    /// let response = registry.sna_response(&request, |_, prop| prop.clone()).unwrap();
+    /// assert_eq!(response.esv, ServiceCode::SetCSNA);
+    /// ```
+    pub fn sna_response<F>(&self, request: &ElPacket, mut process: F) -> Option<ElPacket>
+    where
+        F: FnMut(ServiceCode, &Property) -> Property,
+    {
+        let esv = match request.esv {
+            ServiceCode::SetI => ServiceCode::SetISNA,
+            ServiceCode::SetC => ServiceCode::SetCSNA,
+            ServiceCode::Get => ServiceCode::GetSNA,
+            ServiceCode::InfReq => ServiceCode::InfSNA,
+            ServiceCode::SetGet => ServiceCode::SetGetSNA,
+            _ => return None,
+        };
+        let class = request.deoj.class;
+        let allowed = |esv, prop: &Property| self.is_allowed(class, esv, prop.epc);
+        let lists = access_lists(request);
+        if lists
+            .iter()
+            .all(|(esv, props)| props.iter().all(|prop| allowed(*esv, prop)))
+        {
+            return None;
+        }
+
+        let mut lists = lists.into_iter().map(|(esv, props)| {
+            let props: Vec<Property> = props
+                .iter()
+                .map(|prop| match esv {
+                    _ if allowed(esv, prop) => process(esv, prop),
+                    // not written properties are returned as requested
+                    ServiceCode::SetI | ServiceCode::SetC => prop.clone(),
+                    _ => Property {
+                        epc: prop.epc,
+                        edt: Edt::default(),
+                    },
+                })
+                .collect();
+            Properties::from(props)
+        });
+        let mut response = request.create_response(esv, lists.next().unwrap_or_default());
+        response.get_props = lists.next().unwrap_or_default();
+        Some(response)
+    }
+
+    /// Creates a class specific packet of `eoj` from properties.
+    pub fn decode(&self, eoj: EchonetObject, props: Properties) -> ClassPacket {
+        match self.get(eoj.class.0) {
+            Some(class) => (class.decode)(class, props),
+            None => ClassPacket::Unimplemented(UnimplementedPacket(eoj.class, props)),
+        }
+    }
+
+    /// Creates a class specific packet from the device side object of `packet`.
+    ///
+    /// See [`ElPacket::device_object`].
+    pub fn decode_packet(&self, packet: ElPacket) -> ClassPacket {
+        self.decode(packet.device_object(), packet.props)
+    }
+}
+
+/// Property lists of `request` with the services their access rules are checked for.
+///
+/// The properties to read are empty unless `request` is SetGet.
+fn access_lists(request: &ElPacket) -> [(ServiceCode, &Properties); 2] {
+    let esv = match request.esv {
+        ServiceCode::SetGet => ServiceCode::SetC,
+        esv => esv,
+    };
+    [
+        (esv, &request.props),
+        (ServiceCode::Get, &request.get_props),
+    ]
+}
+
+impl Extend<&'static ClassDef> for ClassRegistry {
+    /// Registers all the class definitions. See [`ClassRegistry::register`].
+    fn extend<T: IntoIterator<Item = &'static ClassDef>>(&mut self, iter: T) {
+        for class in iter {
+            self.register(class);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::object::code;
+    use crate::object::{Access, AccessRule};
+
+    #[test]
+    fn register_replaces_same_class() {
+        static METER: ClassDef = ClassDef {
+            code: code::SMART_METER,
+            name: LocalizedName::new("独自メータ", "Private Meter"),
+            short_name: "Private meter",
+            properties: PropertyMap::Static(&crate::object::SMART_METER_CLASS),
+            releases: ReleaseRange::ALL,
+            decode: ClassPacket::custom,
+        };
+
+        let mut registry = ClassRegistry::default();
+        let num = registry.iter().count();
+        registry.register(&METER);
+        assert_eq!(registry.iter().count(), num);
+
+        let eoj = EchonetObject::from([0x02, 0x88, 0x01]);
+        match registry.decode(eoj, Properties::default()) {
+            ClassPacket::Custom(p) => assert_eq!(p.class().name.en, "Private Meter"),
+            _ => panic!("expected custom packet"),
+        }
+    }
+
+    #[test]
+    fn empty_registry_decodes_unimplemented() {
+        let registry = ClassRegistry::empty();
+        let eoj = EchonetObject::from([0x02, 0x88, 0x01]);
+        assert!(matches!(
+            registry.decode(eoj, Properties::default()),
+            ClassPacket::Unimplemented(_)
+        ));
+    }
+
+    #[test]
+    fn valid_epc_for_release() {
+        static PROPS: phf::Map<u8, PropertyDef> = phf::phf_map! {
+            0xE0u8 => PropertyDef::new("新", "New").releases(ReleaseRange::since(b'F')),
+            0xE1u8 => PropertyDef::new("旧", "Old").releases(ReleaseRange::new(b'C', b'E')),
+        };
+        static CLASS: ClassDef = ClassDef {
+            code: [0x02, 0xF1],
+            name: LocalizedName::new("独自クラス", "Private Class"),
+            short_name: "Private class",
+            properties: PropertyMap::Static(&PROPS),
+            releases: ReleaseRange::since(b'C'),
+            decode: ClassPacket::custom,
+        };
+
+        let mut registry = ClassRegistry::empty();
+        registry.register(&CLASS);
+        let class = ClassCode::new(0x02, 0xF1);
+        let valid = |epc, release| registry.is_valid_epc(class, epc, Release::new(release));
+        assert_eq!(valid(0xE0, b'J'), Some(true));
+        assert_eq!(valid(0xE0, b'E'), Some(false));
+        assert_eq!(valid(0xE1, b'E'), Some(true));
+        assert_eq!(valid(0xE1, b'F'), Some(false));
+        assert_eq!(valid(0x80, b'D'), Some(true));
+        assert_eq!(valid(0xF0, b'D'), Some(false));
+        // the class itself is not defined in release B
+        assert_eq!(valid(0x80, b'B'), Some(false));
+        assert_eq!(
+            registry.is_valid_epc(ClassCode::new(0x02, 0x88), 0x80, Release::new(b'J')),
+            None
+        );
+
+        // the AC properties of the storage battery are added in release J
+        let registry = ClassRegistry::default();
+        let battery = ClassCode::new(0x02, 0x7D);
+        let valid = |epc, release| registry.is_valid_epc(battery, epc, Release::new(release));
+        assert_eq!(valid(0xA0, b'J'), Some(true));
+        assert_eq!(valid(0xA0, b'H'), Some(false));
+        assert_eq!(valid(0xDA, b'H'), Some(true));
+
+        // the classes added in later releases are not valid before them
+        let pcs = ClassCode::new(0x02, 0xA5);
+        assert_eq!(
+            registry.is_valid_epc(pcs, 0xE0, Release::new(b'J')),
+            Some(true)
+        );
+        assert_eq!(
+            registry.is_valid_epc(pcs, 0xE0, Release::new(b'H')),
+            Some(false)
+        );
+        let evps = ClassCode::new(0x02, 0x7E);
+        assert_eq!(
+            registry.is_valid_epc(evps, 0x80, Release::new(b'C')),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn builtin_access_rules() {
+        use crate::{props, ElPacketBuilder};
+
+        let registry = ClassRegistry::default();
+        let request = |esv, props| {
+            ElPacketBuilder::new()
+                .transaction_id(1)
+                .seoj([0x05, 0xFF, 0x01])
+                .deoj([0x02, 0x88, 0x01])
+                .esv(esv)
+                .props(props)
+                .build()
+        };
+
+        // measured instantaneous electric power is Get only
+        let meter = ClassCode::new(0x02, 0x88);
+        assert!(registry.is_allowed(meter, ServiceCode::Get, 0xE7));
+        assert!(!registry.is_allowed(meter, ServiceCode::SetC, 0xE7));
+        let set = request(ServiceCode::SetC, props!([0xE7, [0x00, 0x00, 0x00, 0x01]]));
+        assert!(registry.validate_request(&set).is_err());
+        let set = request(ServiceCode::SetC, props!([0xE5, [0x01]]));
+        assert!(registry.validate_request(&set).is_ok());
+
+        // the super class properties are shared
+        assert!(!registry.is_allowed(meter, ServiceCode::SetC, 0x9F));
+        let aircon = ClassCode::new(0x01, 0x30);
+        assert!(registry.is_allowed(aircon, ServiceCode::SetC, 0xB3));
+        assert!(!registry.is_allowed(aircon, ServiceCode::SetC, 0xBB));
+    }
+
+    #[test]
+    fn sna_for_disallowed_get() {
+        use crate::{props, ElPacketBuilder};
+
+        static PROPS: phf::Map<u8, PropertyDef> = phf::phf_map! {
+            0xE0u8 => PropertyDef::new("読込", "Readable")
+                .access(AccessRule::new(Access::Required, Access::Optional, Access::Optional)),
+            0xE1u8 => PropertyDef::new("書込", "Writable")
+                .access(AccessRule::new(Access::NotApplicable, Access::Required, Access::NotApplicable)),
+            0xE2u8 => PropertyDef::new("読専用", "Read only")
+                .access(AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable)),
+        };
+        static CLASS: ClassDef = ClassDef {
+            code: [0x02, 0xF2],
+            name: LocalizedName::new("独自クラス", "Private Class"),
+            short_name: "Private class",
+            properties: PropertyMap::Static(&PROPS),
+            releases: ReleaseRange::ALL,
+            decode: ClassPacket::custom,
+        };
+        let mut registry = ClassRegistry::empty();
+        registry.register(&CLASS);
+
+        let request = |esv, props| {
+            ElPacketBuilder::new()
+                .transaction_id(3)
+                .seoj([0x05, 0xFF, 0x01])
+                .deoj([0x02, 0xF2, 0x01])
+                .esv(esv)
+                .props(props)
+                .build()
+        };
+        let read = |_, prop: &Property| Property {
+            epc: prop.epc,
+            edt: Edt::new(vec![0x42]),
+        };
+
+        // properties without a definition are allowed
+        let get = request(ServiceCode::Get, props!([0xE0, []], [0xF0, []], [0x90, []]));
+        assert!(registry.validate_request(&get).is_ok());
+        assert!(registry.sna_response(&get, read).is_none());
+
+        let get = request(ServiceCode::Get, props!([0xE0, []], [0xE1, []], [0x90, []]));
+        assert!(registry.validate_request(&get).is_err());
+        let response = registry.sna_response(&get, read).unwrap();
+        assert_eq!(response.esv, ServiceCode::GetSNA);
+        assert!(response.is_response_for(&get));
+        assert_eq!(
+            response.props,
+            props!([0xE0, [0x42]], [0xE1, []], [0x90, [0x42]])
+        );
+
+        // InfReq follows the Get rule, not the announcement rule
+        let inf_req = request(ServiceCode::InfReq, props!([0xE2, []]));
+        assert!(registry.validate_request(&inf_req).is_ok());
+        let inf_req = request(ServiceCode::InfReq, props!([0xE1, []]));
+        assert!(registry.validate_request(&inf_req).is_err());
+
+        let set = request(ServiceCode::SetC, props!([0xE1, [0x01]]));
+        assert!(registry.validate_request(&set).is_ok());
+        let set = request(ServiceCode::SetC, props!([0xE0, [0x01]]));
+        assert!(registry.validate_request(&set).is_ok());
+        let set = request(ServiceCode::SetI, props!([0xE2, [0x01]]));
+        let response = registry.sna_response(&set, read).unwrap();
+        assert_eq!(response.esv, ServiceCode::SetISNA);
+        assert_eq!(response.props, props!([0xE2, [0x01]]));
+
+        // SetGet writes by the Set rule and reads by the Get rule
+        let mut set_get = request(ServiceCode::SetGet, props!([0xE1, [0x01]]));
+        set_get.get_props = props!([0xE0, []]);
+        assert!(registry.validate_request(&set_get).is_ok());
+        assert!(registry.sna_response(&set_get, read).is_none());
+
+        let mut set_get = request(ServiceCode::SetGet, props!([0xE1, [0x01]], [0xE2, [0x02]]));
+        set_get.get_props = props!([0xE0, []], [0xE1, []]);
+        assert!(registry.validate_request(&set_get).is_err());
+        let response = registry
+            .sna_response(&set_get, |esv, prop| match esv {
+                ServiceCode::SetC => Property {
+                    epc: prop.epc,
+                    edt: Edt::default(),
+                },
+                _ => read(esv, prop),
+            })
+            .unwrap();
+        assert_eq!(response.esv, ServiceCode::SetGetSNA);
+        assert_eq!(response.props, props!([0xE1, []], [0xE2, [0x02]]));
+        assert_eq!(response.get_props, props!([0xE0, [0x42]], [0xE1, []]));
+
+        // a read of a write-only property is denied as well
+        let mut set_get = request(ServiceCode::SetGet, props!([0xE1, [0x01]]));
+        set_get.get_props = props!([0xE1, []]);
+        assert!(registry.validate_request(&set_get).is_err());
+    }
+}
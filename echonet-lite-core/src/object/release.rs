@@ -0,0 +1,95 @@
+use core::fmt;
+
+/// Release of the APPENDIX Detailed Requirements for ECHONET Device objects.
+///
+/// Device objects report their release in the third byte of the standard version
+/// information property (EPC 0x82).
+///
+/// # Examples
+///
+/// ```
+/// use echonet_lite::object::{Release, ReleaseRange};
+///
+/// let release = Release::from_standard_version(&[0x00, 0x00, b'J', 0x00]).unwrap();
+/// assert_eq!(release, Release::new(b'J'));
+/// assert!(ReleaseRange::new(b'C', b'J').contains(release));
+/// assert!(!ReleaseRange::since(b'K').contains(release));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Release(u8);
+
+impl Release {
+    /// Creates a release from its letter, e.g. `b'J'`.
+    pub const fn new(letter: u8) -> Self {
+        Self(letter.to_ascii_uppercase())
+    }
+
+    /// Reads the release from the EDT of the standard version information property (EPC 0x82).
+    pub fn from_standard_version(edt: &[u8]) -> Option<Self> {
+        match edt {
+            [_, _, letter, _] if letter.is_ascii_alphabetic() => Some(Self::new(*letter)),
+            _ => None,
+        }
+    }
+
+    /// Letter of the release.
+    pub fn letter(self) -> char {
+        self.0 as char
+    }
+}
+
+impl fmt::Display for Release {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Release {}", self.letter())
+    }
+}
+
+/// Releases in which a class or a property is defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReleaseRange {
+    /// The first release.
+    pub from: Release,
+    /// The last release, `None` if still defined in the latest one.
+    pub to: Option<Release>,
+}
+
+impl ReleaseRange {
+    /// Defined in all the releases.
+    pub const ALL: Self = Self::since(b'A');
+
+    /// Defined from `from` to the latest release.
+    pub const fn since(from: u8) -> Self {
+        Self {
+            from: Release::new(from),
+            to: None,
+        }
+    }
+
+    /// Defined from `from` to `to`.
+    pub const fn new(from: u8, to: u8) -> Self {
+        Self {
+            from: Release::new(from),
+            to: Some(Release::new(to)),
+        }
+    }
+
+    /// Returns true if defined in `release`.
+    pub fn contains(&self, release: Release) -> bool {
+        self.from <= release && self.to.is_none_or(|to| release <= to)
+    }
+}
+
+impl Default for ReleaseRange {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl fmt::Display for ReleaseRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to {
+            Some(to) => write!(f, "{} - {}", self.from.letter(), to.letter()),
+            None => write!(f, "{} -", self.from.letter()),
+        }
+    }
+}
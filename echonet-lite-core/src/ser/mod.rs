@@ -19,9 +19,9 @@ impl Write for Writer {
     }
 }
 
-pub fn serialize<T: ?Sized>(value: &T) -> Result<Vec<u8>, Error>
+pub fn serialize<T>(value: &T) -> Result<Vec<u8>, Error>
 where
-    T: serde::Serialize,
+    T: ?Sized + serde::Serialize,
 {
     let mut writer = Writer(Vec::new());
     let mut serializer = Serializer::new(&mut writer);
@@ -115,9 +115,9 @@ impl<'a, W: Write> serde::Serializer for &'a mut Serializer<W> {
         todo!()
     }
 
-    fn serialize_some<T: ?Sized>(self, _value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_some<T>(self, _value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         todo!()
     }
@@ -135,18 +135,18 @@ impl<'a, W: Write> serde::Serializer for &'a mut Serializer<W> {
         todo!()
     }
 
-    fn serialize_newtype_struct<T: ?Sized>(
+    fn serialize_newtype_struct<T>(
         self,
         _name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         value.serialize(self)
     }
 
-    fn serialize_newtype_variant<T: ?Sized>(
+    fn serialize_newtype_variant<T>(
         self,
         _name: &'static str,
         _variant_index: u32,
@@ -154,7 +154,7 @@ impl<'a, W: Write> serde::Serializer for &'a mut Serializer<W> {
         _value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         todo!()
     }
@@ -222,9 +222,9 @@ where
     type Error = Error;
 
     #[inline]
-    fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_element<T>(&mut self, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::ser::Serialize,
+        T: ?Sized + serde::ser::Serialize,
     {
         value.serialize(&mut *self.ser)
     }
@@ -243,9 +243,9 @@ where
     type Error = Error;
 
     #[inline]
-    fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_element<T>(&mut self, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::ser::Serialize,
+        T: ?Sized + serde::ser::Serialize,
     {
         value.serialize(&mut *self.ser)
     }
@@ -264,9 +264,9 @@ where
     type Error = Error;
 
     #[inline]
-    fn serialize_field<T: ?Sized>(&mut self, value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_field<T>(&mut self, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::ser::Serialize,
+        T: ?Sized + serde::ser::Serialize,
     {
         value.serialize(&mut *self.ser)
     }
@@ -285,9 +285,9 @@ where
     type Error = Error;
 
     #[inline]
-    fn serialize_field<T: ?Sized>(&mut self, value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_field<T>(&mut self, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::ser::Serialize,
+        T: ?Sized + serde::ser::Serialize,
     {
         value.serialize(&mut *self.ser)
     }
@@ -306,17 +306,17 @@ where
     type Error = Error;
 
     #[inline]
-    fn serialize_key<K: ?Sized>(&mut self, value: &K) -> Result<Self::Ok, Self::Error>
+    fn serialize_key<K>(&mut self, value: &K) -> Result<Self::Ok, Self::Error>
     where
-        K: serde::ser::Serialize,
+        K: ?Sized + serde::ser::Serialize,
     {
         value.serialize(&mut *self.ser)
     }
 
     #[inline]
-    fn serialize_value<V: ?Sized>(&mut self, value: &V) -> Result<Self::Ok, Self::Error>
+    fn serialize_value<V>(&mut self, value: &V) -> Result<Self::Ok, Self::Error>
     where
-        V: serde::ser::Serialize,
+        V: ?Sized + serde::ser::Serialize,
     {
         value.serialize(&mut *self.ser)
     }
@@ -335,13 +335,9 @@ where
     type Error = Error;
 
     #[inline]
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        _key: &'static str,
-        value: &T,
-    ) -> Result<Self::Ok, Self::Error>
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::ser::Serialize,
+        T: ?Sized + serde::ser::Serialize,
     {
         value.serialize(&mut *self.ser)
     }
@@ -360,13 +356,9 @@ where
     type Error = Error;
 
     #[inline]
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        _key: &'static str,
-        value: &T,
-    ) -> Result<Self::Ok, Self::Error>
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::ser::Serialize,
+        T: ?Sized + serde::ser::Serialize,
     {
         value.serialize(&mut *self.ser)
     }
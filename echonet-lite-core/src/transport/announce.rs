@@ -0,0 +1,343 @@
+use super::node_profile::NODE_PROFILE;
+use crate::{EchonetObject, ElPacket, ElPacketBuilder, Properties, ServiceCode};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Destination object of InfC.
+const CONTROLLER: [u8; 3] = [0x05, 0xFF, 0x01];
+
+/// How [`ElServer`](super::ElServer) announces the changes of the properties
+/// in the status change announcement property map (0x9D).
+///
+/// # Examples
+///
+/// Changes within 100 ms are sent together, and each object announces at most once a second.
+///
+/// ```
+/// use echonet_lite::transport::Announcement;
+/// use std::time::Duration;
+///
+/// let announcement = Announcement::multicast()
+///     .coalesce(Duration::from_millis(100))
+///     .min_interval(Duration::from_secs(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Announcement {
+    controllers: Vec<SocketAddr>,
+    coalesce: Duration,
+    min_interval: Duration,
+    ack_timeout: Duration,
+    retries: usize,
+}
+
+impl Default for Announcement {
+    fn default() -> Self {
+        Self::multicast()
+    }
+}
+
+impl Announcement {
+    /// Announces with Inf to the multicast groups, the default.
+    pub fn multicast() -> Self {
+        Self {
+            controllers: Vec::new(),
+            coalesce: Duration::ZERO,
+            min_interval: Duration::ZERO,
+            ack_timeout: Duration::from_secs(1),
+            retries: 2,
+        }
+    }
+
+    /// Announces with InfC to each of `controllers`, retransmitting it until InfCRes arrives.
+    pub fn infc(controllers: Vec<SocketAddr>) -> Self {
+        Self {
+            controllers,
+            ..Self::multicast()
+        }
+    }
+
+    /// Time to wait for more changes of the object after a change, 0 by default.
+    ///
+    /// The changes are announced in one packet with the latest values.
+    pub fn coalesce(mut self, window: Duration) -> Self {
+        self.coalesce = window;
+        self
+    }
+
+    /// Minimum interval between the announcements of an object, 0 by default.
+    pub fn min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self
+    }
+
+    /// Time to wait for InfCRes before retransmitting InfC, 1 second by default.
+    pub fn ack_timeout(mut self, timeout: Duration) -> Self {
+        self.ack_timeout = timeout;
+        self
+    }
+
+    /// Number of retransmissions of InfC, 2 by default.
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+}
+
+/// Schedules the announcements of the changes and tracks the acknowledgements of InfC.
+#[derive(Debug, Default)]
+pub(super) struct Announcer {
+    config: Announcement,
+    /// Changed EPCs of each object and when they are announced.
+    pending: BTreeMap<EchonetObject, (BTreeSet<u8>, Instant)>,
+    last_sent: HashMap<EchonetObject, Instant>,
+    /// InfC awaiting InfCRes by transaction ID.
+    unacked: HashMap<u16, Unacked>,
+}
+
+#[derive(Debug)]
+struct Unacked {
+    packet: ElPacket,
+    to: SocketAddr,
+    retries: usize,
+    deadline: Instant,
+}
+
+impl Announcer {
+    pub(super) fn set_config(&mut self, config: Announcement) {
+        self.config = config;
+    }
+
+    /// Schedules the announcement of `epcs` of `eoj`.
+    pub(super) fn changed(&mut self, eoj: EchonetObject, epcs: &BTreeSet<u8>, now: Instant) {
+        if epcs.is_empty() {
+            return;
+        }
+        let config = &self.config;
+        let last_sent = self.last_sent.get(&eoj);
+        let (pending, _) = self.pending.entry(eoj).or_insert_with(|| {
+            let due = now + config.coalesce;
+            let due = last_sent.map_or(due, |sent| due.max(*sent + config.min_interval));
+            (BTreeSet::new(), due)
+        });
+        pending.extend(epcs);
+    }
+
+    /// Returns when [`take_due`](Self::take_due) or [`retransmissions`](Self::retransmissions)
+    /// has something to send next.
+    pub(super) fn deadline(&self) -> Option<Instant> {
+        let due = self.pending.values().map(|(_, due)| *due);
+        let unacked = self.unacked.values().map(|unacked| unacked.deadline);
+        due.chain(unacked).min()
+    }
+
+    /// Takes the objects whose announcements are due with their changed EPCs.
+    pub(super) fn take_due(&mut self, now: Instant) -> Vec<(EchonetObject, Vec<u8>)> {
+        let due: Vec<_> = self
+            .pending
+            .iter()
+            .filter(|(_, (_, due))| *due <= now)
+            .map(|(eoj, _)| *eoj)
+            .collect();
+        due.into_iter()
+            .filter_map(|eoj| {
+                let (epcs, _) = self.pending.remove(&eoj)?;
+                self.last_sent.insert(eoj, now);
+                Some((eoj, epcs.into_iter().collect()))
+            })
+            .collect()
+    }
+
+    /// Creates the announcements of `props` of `eoj` with their destinations,
+    /// where `None` stands for the multicast groups.
+    ///
+    /// An InfC supersedes the unacknowledged ones of `eoj` to the same controller,
+    /// which are not retransmitted any more.
+    pub(super) fn packets(
+        &mut self,
+        eoj: EchonetObject,
+        props: Properties,
+        mut next_tid: impl FnMut() -> u16,
+        now: Instant,
+    ) -> Vec<(ElPacket, Option<SocketAddr>)> {
+        let packet = |tid, esv, deoj| {
+            ElPacketBuilder::new()
+                .transaction_id(tid)
+                .seoj(eoj)
+                .deoj(deoj)
+                .esv(esv)
+                .props(props.clone())
+                .build()
+        };
+        if self.config.controllers.is_empty() {
+            return vec![(packet(next_tid(), ServiceCode::Inf, NODE_PROFILE), None)];
+        }
+
+        let mut packets = Vec::new();
+        for to in self.config.controllers.clone() {
+            self.unacked
+                .retain(|_, unacked| unacked.packet.seoj != eoj || unacked.to != to);
+            let tid = next_tid();
+            let packet = packet(tid, ServiceCode::InfC, CONTROLLER);
+            let unacked = Unacked {
+                packet: packet.clone(),
+                to,
+                retries: self.config.retries,
+                deadline: now + self.config.ack_timeout,
+            };
+            self.unacked.insert(tid, unacked);
+            packets.push((packet, Some(to)));
+        }
+        packets
+    }
+
+    /// Stops retransmitting the InfC acknowledged by `response`.
+    pub(super) fn acked(&mut self, response: &ElPacket, from: SocketAddr) {
+        let tid = response.transaction_id();
+        let acked = self.unacked.get(&tid).is_some_and(|unacked| {
+            response.esv == ServiceCode::InfCRes
+                && unacked.to.ip() == from.ip()
+                && response.seoj == unacked.packet.deoj
+                && response.deoj == unacked.packet.seoj
+        });
+        if acked {
+            self.unacked.remove(&tid);
+        }
+    }
+
+    /// Returns the InfC to retransmit, giving up those retransmitted enough.
+    pub(super) fn retransmissions(&mut self, now: Instant) -> Vec<(ElPacket, SocketAddr)> {
+        let mut packets = Vec::new();
+        let ack_timeout = self.config.ack_timeout;
+        self.unacked.retain(|_, unacked| {
+            if unacked.deadline > now {
+                return true;
+            }
+            if unacked.retries == 0 {
+                return false;
+            }
+            unacked.retries -= 1;
+            unacked.deadline = now + ack_timeout;
+            packets.push((unacked.packet.clone(), unacked.to));
+            true
+        });
+        packets
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{props, Edt, Property};
+
+    const AIRCON: [u8; 3] = [0x01, 0x30, 0x01];
+
+    fn epcs(epcs: &[u8]) -> BTreeSet<u8> {
+        epcs.iter().copied().collect()
+    }
+
+    #[test]
+    fn coalesce_and_rate_limit() {
+        let mut announcer = Announcer::default();
+        announcer.set_config(
+            Announcement::multicast()
+                .coalesce(Duration::from_millis(100))
+                .min_interval(Duration::from_secs(1)),
+        );
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+
+        announcer.changed(AIRCON.into(), &epcs(&[0x80]), at(0));
+        announcer.changed(AIRCON.into(), &epcs(&[0xB0]), at(50));
+        assert_eq!(announcer.deadline(), Some(at(100)));
+        assert!(announcer.take_due(at(99)).is_empty());
+        assert_eq!(
+            announcer.take_due(at(100)),
+            [(AIRCON.into(), vec![0x80, 0xB0])]
+        );
+
+        // the next announcement waits for the interval
+        announcer.changed(AIRCON.into(), &epcs(&[0x80]), at(200));
+        assert_eq!(announcer.deadline(), Some(at(1100)));
+        assert_eq!(announcer.take_due(at(1100)).len(), 1);
+        assert_eq!(announcer.deadline(), None);
+
+        let packets = announcer.packets(AIRCON.into(), props!([0x80, [0x30]]), || 5, at(1100));
+        let (packet, to) = &packets[0];
+        assert_eq!(*to, None);
+        assert_eq!(packet.esv, ServiceCode::Inf);
+        assert_eq!(packet.deoj, NODE_PROFILE.into());
+    }
+
+    #[test]
+    fn infc_acknowledgement() {
+        let controllers: Vec<SocketAddr> = vec![
+            "192.0.2.1:3610".parse().unwrap(),
+            "192.0.2.2:3610".parse().unwrap(),
+        ];
+        let mut announcer = Announcer::default();
+        announcer.set_config(
+            Announcement::infc(controllers.clone())
+                .ack_timeout(Duration::from_millis(500))
+                .retries(1),
+        );
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+
+        let mut tid = 0;
+        let packets = announcer.packets(
+            AIRCON.into(),
+            props!([0x80, [0x30]]),
+            || {
+                tid += 1;
+                tid
+            },
+            at(0),
+        );
+        assert_eq!(packets.len(), 2);
+        let (first, to) = &packets[0];
+        assert_eq!(first.esv, ServiceCode::InfC);
+        assert_eq!(*to, Some(controllers[0]));
+
+        // the first controller acknowledges, the second does not
+        let ack = first.create_response(ServiceCode::InfCRes, props!([0x80, []]));
+        announcer.acked(&ack, controllers[1]);
+        assert_eq!(announcer.unacked.len(), 2);
+        announcer.acked(&ack, controllers[0]);
+        assert_eq!(announcer.deadline(), Some(at(500)));
+
+        let retransmitted = announcer.retransmissions(at(500));
+        assert_eq!(retransmitted, [(packets[1].0.clone(), controllers[1])]);
+        assert!(announcer.retransmissions(at(999)).is_empty());
+        assert!(announcer.retransmissions(at(1000)).is_empty());
+        assert_eq!(announcer.deadline(), None);
+    }
+
+    #[test]
+    fn infc_supersedes_unacknowledged() {
+        let controller: SocketAddr = "192.0.2.1:3610".parse().unwrap();
+        let mut announcer = Announcer::default();
+        announcer.set_config(Announcement::infc(vec![controller]));
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+
+        announcer.packets(AIRCON.into(), props!([0x80, [0x30]]), || 1, at(0));
+        announcer.packets(
+            [0x01, 0x30, 0x02].into(),
+            props!([0x80, [0x30]]),
+            || 2,
+            at(0),
+        );
+        let packets = announcer.packets(AIRCON.into(), props!([0x80, [0x31]]), || 3, at(100));
+        assert_eq!(announcer.unacked.len(), 2);
+
+        // only the latest value of the object is retransmitted
+        let retransmitted = announcer.retransmissions(at(1100));
+        assert_eq!(retransmitted.len(), 2);
+        assert!(retransmitted.contains(&(packets[0].0.clone(), controller)));
+        assert!(retransmitted
+            .iter()
+            .all(|(packet, _)| packet.transaction_id() != 1));
+    }
+}
@@ -0,0 +1,180 @@
+use super::{map, unmap, ElCodec, ElSocketBuilder, IpFamily, MAX_PACKET_SIZE};
+use crate::{ElPacket, Error, Result};
+use bytes::BytesMut;
+use futures_util::stream::{Stream, StreamExt};
+use std::net::{Ipv4Addr, SocketAddr};
+use tokio::net::UdpSocket;
+use tokio_util::codec::Decoder;
+use tokio_util::udp::UdpFramed;
+
+/// A tokio UDP socket sending and receiving ECHONET Lite packets (`async` feature).
+///
+/// This is the asynchronous counterpart of [`ElSocket`](super::ElSocket) and is configured
+/// with [`ElSocketBuilder::bind_async`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use echonet_lite::transport::AsyncElSocket;
+/// use echonet_lite::{prelude::*, props};
+/// use futures_util::StreamExt;
+///
+/// # async fn find() -> echonet_lite::Result<()> {
+/// let socket = AsyncElSocket::bind([192, 168, 1, 10].into())?;
+/// let packet = ElPacketBuilder::new()
+///     .transaction_id(1)
+///     .seoj([0x05, 0xFF, 0x01])
+///     .deoj([0x0E, 0xF0, 0x01])
+///     .esv(ServiceCode::Get)
+///     .props(props!([0x80, []]))
+///     .build();
+/// socket.send_multicast(&packet).await?;
+///
+/// let mut packets = socket.stream();
+/// while let Some((from, response)) = packets.next().await.transpose()? {
+///     println!("{from}: {response}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncElSocket {
+    socket: UdpSocket,
+    family: IpFamily,
+    multicast: Vec<SocketAddr>,
+}
+
+impl AsyncElSocket {
+    pub(super) fn new(socket: UdpSocket, family: IpFamily, multicast: Vec<SocketAddr>) -> Self {
+        Self {
+            socket,
+            family,
+            multicast,
+        }
+    }
+
+    /// Binds port 3610 and joins the IPv4 multicast group on the interface with address `interface`.
+    ///
+    /// Must be called within a tokio runtime.
+    pub fn bind(interface: Ipv4Addr) -> Result<Self> {
+        ElSocketBuilder::new().interface(interface).bind_async()
+    }
+
+    /// Sends `packet` to `addr`.
+    pub async fn send_to(&self, packet: &ElPacket, addr: SocketAddr) -> Result<()> {
+        let bytes = packet.serialize()?;
+        self.socket.send_to(&bytes, map(self.family, addr)).await?;
+        Ok(())
+    }
+
+    /// Sends `packet` to all the nodes in the multicast groups.
+    pub async fn send_multicast(&self, packet: &ElPacket) -> Result<()> {
+        for addr in &self.multicast {
+            self.send_to(packet, *addr).await?;
+        }
+        Ok(())
+    }
+
+    /// Receives a packet and the address of its sender.
+    ///
+    /// Datagrams which are not ECHONET Lite packets are ignored.
+    pub async fn recv_from(&self) -> Result<(ElPacket, SocketAddr)> {
+        let mut buffer = vec![0u8; MAX_PACKET_SIZE];
+        loop {
+            let (len, addr) = self.socket.recv_from(&mut buffer).await?;
+            if let Ok((_, packet)) = ElPacket::from_bytes(&buffer[..len]) {
+                return Ok((packet, unmap(addr)));
+            }
+        }
+    }
+
+    /// Returns the received packets with the addresses of their senders as a stream.
+    ///
+    /// Datagrams which are not ECHONET Lite packets are ignored; the stream only yields
+    /// I/O errors. Packets can still be sent while the stream is alive.
+    pub fn stream(&self) -> impl Stream<Item = Result<(SocketAddr, ElPacket)>> + '_ {
+        UdpFramed::new(&self.socket, SkipInvalid)
+            .map(|received| received.map(|(packet, addr)| (unmap(addr), packet)))
+    }
+
+    /// Returns the address the socket is bound to.
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    pub fn family(&self) -> IpFamily {
+        self.family
+    }
+
+    /// Returns the destinations of [`send_multicast`](Self::send_multicast).
+    pub fn multicast_addrs(&self) -> &[SocketAddr] {
+        &self.multicast
+    }
+}
+
+/// [`ElCodec`] discarding datagrams which can not be decoded instead of failing.
+struct SkipInvalid;
+
+impl Decoder for SkipInvalid {
+    type Item = ElPacket;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<ElPacket>> {
+        Ok(ElCodec.decode(src).ok().flatten())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{props, Edt, ElPacketBuilder, Properties, Property, ServiceCode};
+    use std::time::Duration;
+
+    fn loopback(family: IpFamily) -> AsyncElSocket {
+        ElSocketBuilder::new()
+            .family(family)
+            .interface(Ipv4Addr::LOCALHOST)
+            .port(0)
+            .multicast(false)
+            .bind_async()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn stream_on_loopback() {
+        let controller = loopback(IpFamily::V4);
+        let device = loopback(IpFamily::DualStack);
+        let to = SocketAddr::new(
+            Ipv4Addr::LOCALHOST.into(),
+            device.local_addr().unwrap().port(),
+        );
+        let packet = ElPacketBuilder::new()
+            .transaction_id(1)
+            .seoj([0x05, 0xFF, 0x01])
+            .deoj([0x01, 0x30, 0x01])
+            .esv(ServiceCode::Get)
+            .props(props!([0x80, []]))
+            .build();
+
+        let mut packets = device.stream();
+        // junk and the arbitrary message format are skipped
+        controller.socket.send_to(&[0xFF, 0x00], to).await.unwrap();
+        let mut arbitrary = packet.serialize().unwrap();
+        arbitrary[1] = 0x82;
+        controller.socket.send_to(&arbitrary, to).await.unwrap();
+        controller.send_to(&packet, to).await.unwrap();
+        controller.send_to(&packet, to).await.unwrap();
+        for _ in 0..2 {
+            let (from, received) = packets.next().await.unwrap().unwrap();
+            assert_eq!(from, controller.local_addr().unwrap());
+            assert_eq!(received, packet);
+            // sending while the stream is alive
+            device.send_to(&received, from).await.unwrap();
+        }
+
+        let (received, _) = controller.recv_from().await.unwrap();
+        assert_eq!(received, packet);
+        let timeout = tokio::time::timeout(Duration::from_millis(100), packets.next());
+        assert!(timeout.await.is_err());
+    }
+}
@@ -0,0 +1,669 @@
+use super::subscription::{self, NotificationFilter, Subscribers, Subscription};
+use super::AsyncElSocket;
+use crate::object::{ClassRegistry, Value};
+use crate::{EchonetObject, Edt, ElPacket, ElPacketBuilder, Properties, Property, Result};
+use crate::{ErrorKind, ServiceCode};
+use futures_util::StreamExt;
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+/// A controller sending requests to ECHONET Lite nodes and awaiting their responses
+/// (`async` feature).
+///
+/// Responses are matched to requests by the transaction ID, so any number of requests
+/// can be in flight at once. A request is retransmitted with the same transaction ID
+/// if no response arrives within the timeout.
+///
+/// A request to instance 0 is answered by every instance of the class; [`request`](Self::request)
+/// collects all the responses, while the other methods return the first one.
+///
+/// Property value notifications are delivered to the subscriptions, see
+/// [`subscribe`](Self::subscribe); InfC is acknowledged with InfCRes.
+///
+/// Must be created within a tokio runtime, which runs the task receiving the responses.
+///
+/// # Examples
+///
+/// ```no_run
+/// use echonet_lite::transport::{AsyncElSocket, ElClient};
+///
+/// # async fn get() -> echonet_lite::Result<()> {
+/// let client = ElClient::new(AsyncElSocket::bind([192, 168, 1, 10].into())?);
+/// let to = "192.168.1.20:3610".parse().unwrap();
+/// let response = client.get(to, [0x01, 0x30, 0x01].into(), &[0x80, 0xB3]).await?;
+/// if let Some(edt) = response.value(0x80) {
+///     println!("operation status: {edt}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ElClient {
+    shared: Arc<Shared>,
+    receiver: JoinHandle<()>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    socket: AsyncElSocket,
+    seoj: EchonetObject,
+    timeout: Duration,
+    retries: usize,
+    registry: ClassRegistry,
+    pending: Mutex<Pending>,
+    subscribers: Subscribers,
+}
+
+#[derive(Debug, Default)]
+struct Pending {
+    next_tid: u16,
+    requests: HashMap<u16, Request>,
+}
+
+/// A request awaiting its response.
+#[derive(Debug)]
+struct Request {
+    packet: ElPacket,
+    to: SocketAddr,
+    responses: mpsc::UnboundedSender<Response>,
+}
+
+impl ElClient {
+    /// Creates a client with the default configuration of [`ElClientBuilder`].
+    pub fn new(socket: AsyncElSocket) -> Self {
+        ElClientBuilder::new().build(socket)
+    }
+
+    /// Reads the properties `epcs` of `deoj` with Get.
+    pub async fn get(&self, to: SocketAddr, deoj: EchonetObject, epcs: &[u8]) -> Result<Response> {
+        self.send(
+            to,
+            deoj,
+            ServiceCode::Get,
+            read(epcs),
+            Properties::default(),
+        )
+        .await
+    }
+
+    /// Writes `props` to `deoj` with SetC.
+    pub async fn set(
+        &self,
+        to: SocketAddr,
+        deoj: EchonetObject,
+        props: Properties,
+    ) -> Result<Response> {
+        self.send(to, deoj, ServiceCode::SetC, props, Properties::default())
+            .await
+    }
+
+    /// Writes `props` to `deoj` and then reads the properties `epcs` with SetGet.
+    pub async fn set_get(
+        &self,
+        to: SocketAddr,
+        deoj: EchonetObject,
+        props: Properties,
+        epcs: &[u8],
+    ) -> Result<Response> {
+        self.send(to, deoj, ServiceCode::SetGet, props, read(epcs))
+            .await
+    }
+
+    /// Requests `deoj` to notify the properties `epcs` with InfReq.
+    ///
+    /// The notification may be sent by multicast; it is still matched by its transaction ID.
+    pub async fn inf_req(
+        &self,
+        to: SocketAddr,
+        deoj: EchonetObject,
+        epcs: &[u8],
+    ) -> Result<Response> {
+        self.send(
+            to,
+            deoj,
+            ServiceCode::InfReq,
+            read(epcs),
+            Properties::default(),
+        )
+        .await
+    }
+
+    async fn send(
+        &self,
+        to: SocketAddr,
+        deoj: EchonetObject,
+        esv: ServiceCode,
+        props: Properties,
+        get_props: Properties,
+    ) -> Result<Response> {
+        let packet = ElPacketBuilder::new()
+            .seoj(self.shared.seoj)
+            .deoj(deoj)
+            .esv(esv)
+            .props(props)
+            .get_props(get_props)
+            .build();
+        let mut responses = self.request(to, packet).await?;
+        Ok(responses.swap_remove(0))
+    }
+
+    /// Sends `packet` to `to` with a new transaction ID and awaits the response.
+    ///
+    /// If the destination object is instance 0, the responses of the instances arriving
+    /// within the timeout after the first one are collected as well.
+    ///
+    /// The transaction ID of `packet` is replaced.
+    /// Returns an error satisfying [`ErrorKind::is_timeout`] if no response arrives after
+    /// the retransmissions.
+    pub async fn request(&self, to: SocketAddr, packet: ElPacket) -> Result<Vec<Response>> {
+        let wildcard = packet.deoj.instance() == 0;
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let (tid, packet) = self.shared.register(to, packet, sender);
+        // forgets the request even if the caller stops awaiting
+        let _guard = Unregister {
+            shared: &self.shared,
+            tid,
+        };
+
+        for _ in 0..=self.shared.retries {
+            self.shared.socket.send_to(&packet, to).await?;
+            match tokio::time::timeout(self.shared.timeout, receiver.recv()).await {
+                Ok(Some(response)) => {
+                    let mut responses = vec![response];
+                    if wildcard {
+                        let deadline = Instant::now() + self.shared.timeout;
+                        while let Ok(Some(response)) =
+                            tokio::time::timeout_at(deadline, receiver.recv()).await
+                        {
+                            // an instance may also answer an earlier transmission
+                            let duplicate = responses.iter().any(|received| {
+                                received.from == response.from
+                                    && received.packet.seoj == response.packet.seoj
+                            });
+                            if !duplicate {
+                                responses.push(response);
+                            }
+                        }
+                    }
+                    return Ok(responses);
+                }
+                Ok(None) => break,
+                Err(_) => continue,
+            }
+        }
+        Err(ErrorKind::Io(io::ErrorKind::TimedOut.into()).into())
+    }
+
+    /// Sends `packet` to the multicast groups and collects the responses arriving within `window`.
+    ///
+    /// The transaction ID of `packet` is replaced. The request is not retransmitted.
+    pub async fn multicast(&self, packet: ElPacket, window: Duration) -> Result<Vec<Response>> {
+        self.collect(None, packet, window).await
+    }
+
+    /// Sends `packet` to `to`, or to the multicast groups if it is `None`, and collects
+    /// the responses arriving within `window`.
+    pub(super) async fn collect(
+        &self,
+        to: Option<SocketAddr>,
+        packet: ElPacket,
+        window: Duration,
+    ) -> Result<Vec<Response>> {
+        let multicast = self.shared.socket.multicast_addrs().first().copied();
+        let Some(dest) = to.or(multicast) else {
+            let err = io::Error::new(io::ErrorKind::InvalidInput, "no multicast group joined");
+            return Err(ErrorKind::Io(err).into());
+        };
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let (tid, packet) = self.shared.register(dest, packet, sender);
+        let _guard = Unregister {
+            shared: &self.shared,
+            tid,
+        };
+
+        match to {
+            Some(to) => self.shared.socket.send_to(&packet, to).await?,
+            None => self.shared.socket.send_multicast(&packet).await?,
+        }
+        let deadline = Instant::now() + window;
+        let mut responses = Vec::new();
+        while let Ok(Some(response)) = tokio::time::timeout_at(deadline, receiver.recv()).await {
+            responses.push(response);
+        }
+        Ok(responses)
+    }
+
+    /// Subscribes to the Inf and InfC notifications matching `filter`.
+    ///
+    /// The notified values are decoded with the classes of
+    /// [`ElClientBuilder::registry`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use echonet_lite::transport::{AsyncElSocket, ElClient, NotificationFilter};
+    ///
+    /// # async fn watch() -> echonet_lite::Result<()> {
+    /// let client = ElClient::new(AsyncElSocket::bind([192, 168, 1, 10].into())?);
+    /// let mut subscription = client.subscribe(NotificationFilter::new().epc(0x80));
+    /// while let Some(notification) = subscription.recv().await {
+    ///     for prop in &notification.properties {
+    ///         match prop.value {
+    ///             Some(value) => println!("{} {:02X}: {value}", notification.eoj, prop.epc),
+    ///             None => println!("{} {:02X}: {}", notification.eoj, prop.epc, prop.edt),
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscribe(&self, filter: NotificationFilter) -> Subscription {
+        self.shared.subscribers.subscribe(filter)
+    }
+
+    /// Returns the source object of the requests.
+    pub fn seoj(&self) -> EchonetObject {
+        self.shared.seoj
+    }
+
+    /// Returns the socket, e.g. to send packets without awaiting responses.
+    pub fn socket(&self) -> &AsyncElSocket {
+        &self.shared.socket
+    }
+}
+
+impl Drop for ElClient {
+    fn drop(&mut self) {
+        self.receiver.abort();
+    }
+}
+
+impl Shared {
+    /// Stores the request with an unused transaction ID and returns the packet to send.
+    fn register(
+        &self,
+        to: SocketAddr,
+        packet: ElPacket,
+        sender: mpsc::UnboundedSender<Response>,
+    ) -> (u16, ElPacket) {
+        let mut pending = self.pending.lock().unwrap();
+        let mut tid = pending.next_tid;
+        while pending.requests.contains_key(&tid) {
+            tid = tid.wrapping_add(1);
+        }
+        pending.next_tid = tid.wrapping_add(1);
+
+        let packet = ElPacketBuilder::new()
+            .transaction_id(tid)
+            .seoj(packet.seoj)
+            .deoj(packet.deoj)
+            .esv(packet.esv)
+            .props(packet.props)
+            .get_props(packet.get_props)
+            .build();
+        let request = Request {
+            packet: packet.clone(),
+            to,
+            responses: sender,
+        };
+        pending.requests.insert(tid, request);
+        (tid, packet)
+    }
+
+    /// Passes `packet` to the request it responds to, if any.
+    fn dispatch(&self, from: SocketAddr, packet: ElPacket) {
+        let pending = self.pending.lock().unwrap();
+        let Some(request) = pending.requests.get(&packet.transaction_id()) else {
+            return;
+        };
+        // responses to multicast requests come from any node
+        let same_node = request.to.ip().is_multicast() || request.to.ip() == from.ip();
+        // own multicast requests may loop back
+        let is_response = packet.is_response_for(&request.packet) && !packet.esv.is_request();
+        if same_node && is_response {
+            let _ = request.responses.send(Response { from, packet });
+        }
+    }
+}
+
+/// Removes the request from the pending ones when dropped.
+struct Unregister<'a> {
+    shared: &'a Shared,
+    tid: u16,
+}
+
+impl Drop for Unregister<'_> {
+    fn drop(&mut self) {
+        self.shared
+            .pending
+            .lock()
+            .unwrap()
+            .requests
+            .remove(&self.tid);
+    }
+}
+
+fn read(epcs: &[u8]) -> Properties {
+    epcs.iter()
+        .map(|&epc| Property {
+            epc,
+            edt: Edt::default(),
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Configures an [`ElClient`].
+#[derive(Debug, Clone)]
+pub struct ElClientBuilder {
+    seoj: EchonetObject,
+    timeout: Duration,
+    retries: usize,
+    registry: ClassRegistry,
+}
+
+impl Default for ElClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElClientBuilder {
+    pub fn new() -> Self {
+        Self {
+            seoj: [0x05, 0xFF, 0x01].into(),
+            timeout: Duration::from_secs(1),
+            retries: 2,
+            registry: ClassRegistry::default(),
+        }
+    }
+
+    /// Source object of the requests, the controller 05FF01 by default.
+    pub fn seoj(mut self, seoj: EchonetObject) -> Self {
+        self.seoj = seoj;
+        self
+    }
+
+    /// Time to wait for a response before retransmitting, 1 second by default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Number of retransmissions after the first request, 2 by default.
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Class definitions decoding the notified values, the built-in classes by default.
+    pub fn registry(mut self, registry: ClassRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Creates the client and starts receiving on `socket`. Must be called within a tokio runtime.
+    pub fn build(self, socket: AsyncElSocket) -> ElClient {
+        let shared = Arc::new(Shared {
+            socket,
+            seoj: self.seoj,
+            timeout: self.timeout,
+            retries: self.retries,
+            registry: self.registry,
+            pending: Mutex::default(),
+            subscribers: Subscribers::default(),
+        });
+        let receiver = tokio::spawn({
+            let shared = shared.clone();
+            async move {
+                let mut packets = shared.socket.stream();
+                while let Some(received) = packets.next().await {
+                    // e.g. ICMP port unreachable of a previous request
+                    let Ok((from, packet)) = received else {
+                        continue;
+                    };
+                    if let Some(ack) = subscription::acknowledgement(&packet) {
+                        // the notifier retransmits if the acknowledgement is lost
+                        let _ = shared.socket.send_to(&ack, from).await;
+                    }
+                    shared.subscribers.notify(from, &packet, &shared.registry);
+                    shared.dispatch(from, packet);
+                }
+            }
+        });
+        ElClient { shared, receiver }
+    }
+}
+
+/// A response to a request of [`ElClient`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    /// Address of the responding node.
+    pub from: SocketAddr,
+    /// The response packet.
+    pub packet: ElPacket,
+}
+
+/// The result of a property in a [`Response`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropertyResult {
+    /// The property was read; the EDT is its value.
+    Read(Property),
+    /// The property was written.
+    Written(u8),
+    /// The node could not process the property; a rejected write echoes the EDT back.
+    Rejected(Property),
+}
+
+impl PropertyResult {
+    pub fn epc(&self) -> u8 {
+        match self {
+            PropertyResult::Read(prop) | PropertyResult::Rejected(prop) => prop.epc,
+            PropertyResult::Written(epc) => *epc,
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        !matches!(self, PropertyResult::Rejected(_))
+    }
+}
+
+impl Response {
+    /// Returns true unless the node responded with SNA (response not possible).
+    pub fn is_success(&self) -> bool {
+        !matches!(
+            self.packet.esv,
+            ServiceCode::SetISNA
+                | ServiceCode::SetCSNA
+                | ServiceCode::GetSNA
+                | ServiceCode::InfSNA
+                | ServiceCode::SetGetSNA
+        )
+    }
+
+    /// Returns the results of all the properties, the written ones of SetGet first.
+    ///
+    /// Even a successful SNA response has the properties which could be processed.
+    pub fn results(&self) -> Vec<PropertyResult> {
+        let packet = &self.packet;
+        let written = |prop: &Property| {
+            if prop.edt.is_empty() {
+                PropertyResult::Written(prop.epc)
+            } else {
+                PropertyResult::Rejected(prop.clone())
+            }
+        };
+        let read = |prop: &Property| {
+            if prop.edt.is_empty() {
+                PropertyResult::Rejected(prop.clone())
+            } else {
+                PropertyResult::Read(prop.clone())
+            }
+        };
+
+        match packet.esv {
+            ServiceCode::SetRes | ServiceCode::SetCSNA | ServiceCode::SetISNA => {
+                packet.props.iter().map(written).collect()
+            }
+            ServiceCode::SetGetRes | ServiceCode::SetGetSNA => packet
+                .props
+                .iter()
+                .map(written)
+                .chain(packet.get_props.iter().map(read))
+                .collect(),
+            _ => packet.props.iter().map(read).collect(),
+        }
+    }
+
+    /// Returns the value of `epc` if it was read.
+    pub fn value(&self, epc: u8) -> Option<&Edt> {
+        let props = if self.packet.esv.is_set_get() {
+            &self.packet.get_props
+        } else {
+            &self.packet.props
+        };
+        props
+            .iter()
+            .find(|prop| prop.epc == epc && !prop.edt.is_empty())
+            .map(|prop| &prop.edt)
+    }
+
+    /// Decodes the value of `epc` with the class definition of the responding object.
+    pub fn decode(&self, registry: &ClassRegistry, epc: u8) -> Option<Value> {
+        let class = registry.get(self.packet.seoj.class_code().as_bytes())?;
+        class.property(epc)?.decode(self.value(epc)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::transport::{ElSocketBuilder, IpFamily};
+    use crate::{bulk_read, props};
+    use std::net::Ipv4Addr;
+
+    fn loopback() -> AsyncElSocket {
+        ElSocketBuilder::new()
+            .family(IpFamily::V4)
+            .interface(Ipv4Addr::LOCALHOST)
+            .port(0)
+            .multicast(false)
+            .bind_async()
+            .unwrap()
+    }
+
+    fn client() -> ElClient {
+        ElClientBuilder::new()
+            .timeout(Duration::from_millis(200))
+            .retries(1)
+            .build(loopback())
+    }
+
+    /// Receives `n` requests and answers them in reverse order.
+    async fn respond(device: &AsyncElSocket, n: usize, skip: usize) {
+        let mut requests = Vec::new();
+        while requests.len() < n + skip {
+            requests.push(device.recv_from().await.unwrap());
+        }
+        for (request, from) in requests.into_iter().skip(skip).rev() {
+            let response = match request.esv {
+                ServiceCode::Get => {
+                    let props = request
+                        .props
+                        .iter()
+                        .map(|prop| match prop.epc {
+                            0x80 => crate::prop!(0x80, [0x30]),
+                            epc => crate::prop!(epc, []),
+                        })
+                        .collect::<Vec<_>>();
+                    let esv = if props.iter().all(|prop| !prop.edt.is_empty()) {
+                        ServiceCode::GetRes
+                    } else {
+                        ServiceCode::GetSNA
+                    };
+                    request.create_response(esv, props.into())
+                }
+                _ => {
+                    let mut response =
+                        request.create_response(ServiceCode::SetGetRes, props!([0x80, []]));
+                    response.get_props = props!([0x80, [0x31]]);
+                    response
+                }
+            };
+            device.send_to(&response, from).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn requests_in_flight() {
+        let client = client();
+        let device = loopback();
+        let to = device.local_addr().unwrap();
+        let eoj = EchonetObject::from([0x01, 0x30, 0x01]);
+
+        let (get, set_get, ()) = tokio::join!(
+            client.get(to, eoj, &[0x80, 0xB0]),
+            client.set_get(to, eoj, props!([0x80, [0x31]]), &[0x80]),
+            respond(&device, 2, 0),
+        );
+        let get = get.unwrap();
+        assert!(!get.is_success());
+        assert_eq!(
+            get.results(),
+            [
+                PropertyResult::Read(crate::prop!(0x80, [0x30])),
+                PropertyResult::Rejected(crate::prop!(0xB0, [])),
+            ]
+        );
+        assert_eq!(
+            get.decode(&ClassRegistry::default(), 0x80)
+                .unwrap()
+                .to_string(),
+            "ON"
+        );
+
+        let set_get = set_get.unwrap();
+        assert_eq!(set_get.from, to);
+        assert_eq!(set_get.results()[0], PropertyResult::Written(0x80));
+        assert_eq!(&**set_get.value(0x80).unwrap(), [0x31]);
+        assert!(client.shared.pending.lock().unwrap().requests.is_empty());
+    }
+
+    #[tokio::test]
+    async fn retransmission_and_timeout() {
+        let client = client();
+        let device = loopback();
+        let to = device.local_addr().unwrap();
+        let eoj = EchonetObject::from([0x01, 0x30, 0x01]);
+
+        // the first request is lost
+        let (response, ()) = tokio::join!(client.get(to, eoj, &[0x80]), respond(&device, 1, 1));
+        assert!(response.unwrap().is_success());
+
+        // a response from another object does not match
+        let request = ElPacketBuilder::new()
+            .seoj([0x05, 0xFF, 0x01])
+            .deoj([0x01, 0x30, 0x02])
+            .esv(ServiceCode::Get)
+            .props(bulk_read!(0x80))
+            .build();
+        let wrong_object = async {
+            for _ in 0..2 {
+                let (request, from) = device.recv_from().await.unwrap();
+                let mut response =
+                    request.create_response(ServiceCode::GetRes, props!([0x80, [0x30]]));
+                response.seoj = eoj;
+                device.send_to(&response, from).await.unwrap();
+            }
+        };
+        let (response, ()) = tokio::join!(client.request(to, request), wrong_object);
+        let err = response.unwrap_err();
+        assert!(err.is_timeout(), "{err}");
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{ElPacket, Error, Result};
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+/// A `tokio-util` codec of ECHONET Lite packets.
+///
+/// Each buffer is expected to hold a whole datagram, as with `UdpFramed`.
+/// A buffer which is not an ECHONET Lite packet is discarded with the error,
+/// so that the following datagrams can still be decoded.
+///
+/// # Examples
+///
+/// ```
+/// use bytes::BytesMut;
+/// use echonet_lite::transport::ElCodec;
+/// use echonet_lite::{prelude::*, props};
+/// use tokio_util::codec::{Decoder, Encoder};
+///
+/// let packet = ElPacketBuilder::new()
+///     .transaction_id(1)
+///     .seoj([0x05, 0xFF, 0x01])
+///     .deoj([0x0E, 0xF0, 0x01])
+///     .esv(ServiceCode::Get)
+///     .props(props!([0x80, []]))
+///     .build();
+///
+/// let mut buffer = BytesMut::new();
+/// ElCodec.encode(packet.clone(), &mut buffer).unwrap();
+/// assert_eq!(ElCodec.decode(&mut buffer).unwrap(), Some(packet));
+/// assert!(buffer.is_empty());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ElCodec;
+
+impl Decoder for ElCodec {
+    type Item = ElPacket;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<ElPacket>> {
+        if src.is_empty() {
+            return Ok(None);
+        }
+        match ElPacket::from_bytes(src) {
+            Ok((len, packet)) => {
+                src.advance(len);
+                Ok(Some(packet))
+            }
+            Err(err) => {
+                // the rest of the datagram can not be resynchronized
+                src.clear();
+                Err(err)
+            }
+        }
+    }
+}
+
+impl Encoder<ElPacket> for ElCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: ElPacket, dst: &mut BytesMut) -> Result<()> {
+        dst.extend_from_slice(&item.serialize()?);
+        Ok(())
+    }
+}
+
+impl Encoder<&ElPacket> for ElCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: &ElPacket, dst: &mut BytesMut) -> Result<()> {
+        dst.extend_from_slice(&item.serialize()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,331 @@
+use super::node_profile::{IDENTIFICATION, INSTANCE_LIST, MANUFACTURER, NODE_PROFILE};
+use super::{ElClient, Response};
+use crate::object::{decode_object_list, decode_property_map};
+use crate::ServiceCode;
+use crate::{bulk_read, EchonetObject, Edt, ElPacketBuilder, Properties, Property, Result};
+use futures_util::future::join_all;
+use std::fmt;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+/// Properties fetched from each object besides the identification and the manufacturer.
+const ANNOUNCE_MAP: u8 = 0x9D;
+const SET_MAP: u8 = 0x9E;
+const GET_MAP: u8 = 0x9F;
+
+/// An ECHONET Lite node found by [`ElClient::discover`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    /// Address of the node.
+    pub addr: SocketAddr,
+    /// The node profile followed by the objects in its instance list.
+    pub objects: Vec<NodeObject>,
+}
+
+impl Node {
+    /// Returns the node profile object.
+    pub fn profile(&self) -> Option<&NodeObject> {
+        self.objects
+            .iter()
+            .find(|object| object.eoj.is_node_profile())
+    }
+
+    /// Returns the objects other than the node profile.
+    pub fn devices(&self) -> impl Iterator<Item = &NodeObject> {
+        self.objects
+            .iter()
+            .filter(|object| !object.eoj.is_node_profile())
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.addr)?;
+        for object in &self.objects {
+            write!(f, "{object}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An object of a [`Node`] with its identification and property maps.
+///
+/// Properties the object did not respond with are `None` or empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeObject {
+    pub eoj: EchonetObject,
+    /// Identification number (0x83).
+    pub identification: Option<Vec<u8>>,
+    /// Manufacturer code (0x8A).
+    pub manufacturer: Option<[u8; 3]>,
+    /// EPCs in the status change announcement property map (0x9D).
+    pub announce: Vec<u8>,
+    /// EPCs in the Set property map (0x9E).
+    pub set: Vec<u8>,
+    /// EPCs in the Get property map (0x9F).
+    pub get: Vec<u8>,
+}
+
+impl NodeObject {
+    fn new(eoj: EchonetObject) -> Self {
+        Self {
+            eoj,
+            identification: None,
+            manufacturer: None,
+            announce: Vec::new(),
+            set: Vec::new(),
+            get: Vec::new(),
+        }
+    }
+
+    fn from_response(eoj: EchonetObject, response: &Response) -> Self {
+        let map = |epc| {
+            response
+                .value(epc)
+                .and_then(|edt| decode_property_map(edt))
+                .unwrap_or_default()
+        };
+        Self {
+            eoj,
+            identification: response.value(IDENTIFICATION).map(|edt| edt.to_vec()),
+            manufacturer: response
+                .value(MANUFACTURER)
+                .and_then(|edt| (**edt).try_into().ok()),
+            announce: map(ANNOUNCE_MAP),
+            set: map(SET_MAP),
+            get: map(GET_MAP),
+        }
+    }
+
+    /// Returns all the EPCs supported by the object in ascending order.
+    pub fn epcs(&self) -> Vec<u8> {
+        let mut epcs = [self.announce.as_slice(), &self.set, &self.get].concat();
+        epcs.sort_unstable();
+        epcs.dedup();
+        epcs
+    }
+}
+
+impl fmt::Display for NodeObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "  {}", self.eoj)?;
+        if let Some(code) = self.manufacturer {
+            write!(
+                f,
+                " manufacturer {:02X}{:02X}{:02X}",
+                code[0], code[1], code[2]
+            )?;
+        }
+        writeln!(f)?;
+        for (name, epcs) in [
+            ("Get", &self.get),
+            ("Set", &self.set),
+            ("Announce", &self.announce),
+        ] {
+            write!(f, "    {name}:")?;
+            for epc in epcs {
+                write!(f, " {epc:02X}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl ElClient {
+    /// Finds the nodes by multicasting a Get of the instance list (0xD6) to the node profiles
+    /// and fetches the identification, the manufacturer and the property maps of their objects.
+    ///
+    /// Responses arriving within `window` are collected. Objects which do not respond are listed
+    /// without their properties, and nodes whose objects cannot be requested are left out.
+    pub async fn discover(&self, window: Duration) -> Result<Vec<Node>> {
+        self.discover_at(None, window).await
+    }
+
+    /// Finds the nodes like [`discover`](Self::discover), but sends the Get of the instance list
+    /// to `to` if it is given, e.g. a node beyond the reach of multicast.
+    pub async fn discover_at(&self, to: Option<SocketAddr>, window: Duration) -> Result<Vec<Node>> {
+        let request = ElPacketBuilder::new()
+            .seoj(self.seoj())
+            .deoj(NODE_PROFILE)
+            .esv(ServiceCode::Get)
+            .props(bulk_read!(INSTANCE_LIST))
+            .build();
+        let mut responses = self.collect(to, request, window).await?;
+        // the same node may respond more than once
+        let mut addrs = Vec::new();
+        responses.retain(|response| {
+            let new = !addrs.contains(&response.from);
+            addrs.push(response.from);
+            new
+        });
+
+        let nodes = responses
+            .iter()
+            .map(|response| self.inventory(response.from, instance_list(response)));
+        let nodes = join_all(nodes).await.into_iter().filter_map(Result::ok);
+        Ok(nodes.collect())
+    }
+
+    /// Fetches the objects of the node at `addr` like [`discover`](Self::discover).
+    pub async fn node(&self, addr: SocketAddr) -> Result<Node> {
+        let response = self
+            .get(addr, NODE_PROFILE.into(), &[INSTANCE_LIST])
+            .await?;
+        self.inventory(addr, instance_list(&response)).await
+    }
+
+    async fn inventory(&self, addr: SocketAddr, devices: Vec<EchonetObject>) -> Result<Node> {
+        let eojs = [NODE_PROFILE.into()].into_iter().chain(devices);
+        let objects = eojs.map(|eoj| async move {
+            let epcs = [IDENTIFICATION, MANUFACTURER, ANNOUNCE_MAP, SET_MAP, GET_MAP];
+            match self.get(addr, eoj, &epcs).await {
+                Ok(response) => Ok(NodeObject::from_response(eoj, &response)),
+                Err(err) if err.is_timeout() => Ok(NodeObject::new(eoj)),
+                Err(err) => Err(err),
+            }
+        });
+        let objects = join_all(objects).await.into_iter().collect::<Result<_>>()?;
+        Ok(Node { addr, objects })
+    }
+}
+
+/// Device objects in the instance list of the response, empty if it is invalid.
+fn instance_list(response: &Response) -> Vec<EchonetObject> {
+    let list = response
+        .value(INSTANCE_LIST)
+        .and_then(|edt| decode_object_list(edt))
+        .unwrap_or_default();
+    list.into_iter()
+        .filter(|eoj| !eoj.is_node_profile())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::object::encode_property_map;
+    use crate::props;
+    use crate::transport::{AsyncElSocket, ElClientBuilder, ElSocketBuilder};
+    use std::collections::HashMap;
+    use std::net::Ipv4Addr;
+
+    fn loopback() -> AsyncElSocket {
+        ElSocketBuilder::new()
+            .interface(Ipv4Addr::LOCALHOST)
+            .port(0)
+            .multicast(false)
+            .bind_async()
+            .unwrap()
+    }
+
+    /// Answers `n` Get requests with the properties in `values`.
+    async fn respond(
+        node: &AsyncElSocket,
+        values: &HashMap<(EchonetObject, u8), Vec<u8>>,
+        n: usize,
+    ) {
+        for _ in 0..n {
+            let (request, from) = node.recv_from().await.unwrap();
+            let props: Vec<_> = request
+                .props
+                .iter()
+                .map(|prop| Property {
+                    epc: prop.epc,
+                    edt: Edt::new(
+                        values
+                            .get(&(request.deoj, prop.epc))
+                            .cloned()
+                            .unwrap_or_default(),
+                    ),
+                })
+                .collect();
+            let esv = if props.iter().all(|prop| !prop.edt.is_empty()) {
+                ServiceCode::GetRes
+            } else {
+                ServiceCode::GetSNA
+            };
+            let response = request.create_response(esv, Properties::from(props));
+            node.send_to(&response, from).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn node_inventory() {
+        let client = ElClientBuilder::new()
+            .timeout(Duration::from_millis(200))
+            .build(loopback());
+        let node = loopback();
+        let addr = node.local_addr().unwrap();
+
+        let profile = EchonetObject::from(NODE_PROFILE);
+        let aircon = EchonetObject::from([0x01, 0x30, 0x01]);
+        let values = HashMap::from([
+            ((profile, 0xD6), vec![0x01, 0x01, 0x30, 0x01]),
+            ((profile, 0x83), [0xFE].repeat(17)),
+            ((profile, 0x8A), vec![0x00, 0x00, 0x77]),
+            (
+                (profile, 0x9F),
+                encode_property_map(&[0x80, 0x82, 0x83, 0x8A, 0xD6]),
+            ),
+            ((aircon, 0x8A), vec![0x00, 0x00, 0x77]),
+            ((aircon, 0x9D), encode_property_map(&[0x80])),
+            ((aircon, 0x9E), encode_property_map(&[0x80, 0xB0, 0xB3])),
+            (
+                (aircon, 0x9F),
+                encode_property_map(&(0x80..0x90).collect::<Vec<_>>()),
+            ),
+        ]);
+
+        let (inventory, ()) = tokio::join!(client.node(addr), respond(&node, &values, 3));
+        let inventory = inventory.unwrap();
+        assert_eq!(inventory.addr, addr);
+        let profile = inventory.profile().unwrap();
+        assert_eq!(profile.identification.as_ref().unwrap().len(), 17);
+        assert_eq!(profile.get, [0x80, 0x82, 0x83, 0x8A, 0xD6]);
+
+        let devices: Vec<_> = inventory.devices().collect();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].eoj, aircon);
+        assert_eq!(devices[0].manufacturer, Some([0x00, 0x00, 0x77]));
+        assert_eq!(devices[0].identification, None);
+        assert_eq!(devices[0].set, [0x80, 0xB0, 0xB3]);
+        assert_eq!(devices[0].epcs().len(), 18);
+    }
+
+    #[tokio::test]
+    async fn discover_at_unicast() {
+        let client = ElClientBuilder::new()
+            .timeout(Duration::from_millis(200))
+            .build(loopback());
+        let node = loopback();
+        let other = loopback();
+        let addr = node.local_addr().unwrap();
+        let aircon = EchonetObject::from([0x01, 0x30, 0x01]);
+        let values = HashMap::from([((aircon, 0x8A), vec![0x00, 0x00, 0x77])]);
+        let no_values = HashMap::new();
+
+        // the node answers twice and another node on the same host answers as well
+        let nodes = async {
+            let (request, from) = node.recv_from().await.unwrap();
+            let list = props!([0xD6, [0x01, 0x01, 0x30, 0x01]]);
+            let response = request.create_response(ServiceCode::GetRes, list);
+            node.send_to(&response, from).await.unwrap();
+            node.send_to(&response, from).await.unwrap();
+            let response = request.create_response(ServiceCode::GetRes, props!([0xD6, [0x00]]));
+            other.send_to(&response, from).await.unwrap();
+            tokio::join!(respond(&node, &values, 2), respond(&other, &no_values, 1));
+        };
+        let window = Duration::from_millis(100);
+        let (found, ()) = tokio::join!(client.discover_at(Some(addr), window), nodes);
+        let found = found.unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].addr, addr);
+        let devices: Vec<_> = found[0].devices().collect();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].manufacturer, Some([0x00, 0x00, 0x77]));
+        assert_eq!(found[1].addr, other.local_addr().unwrap());
+        assert!(found[1].profile().is_some());
+        assert_eq!(found[1].devices().count(), 0);
+    }
+}
@@ -0,0 +1,432 @@
+//! Blocking UDP transport of ECHONET Lite packets (`transport` feature).
+//!
+//! Sockets use IPv4 (224.0.23.0), IPv6 (ff02::1) or both of them.
+//! IPv6 interfaces are specified with their index, which is the scope ID of the link-local addresses.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use echonet_lite::transport::ElSocket;
+//! use echonet_lite::{prelude::*, props};
+//! use std::time::Duration;
+//!
+//! let socket = ElSocket::bind([192, 168, 1, 10].into()).unwrap();
+//! socket.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+//!
+//! let packet = ElPacketBuilder::new()
+//!     .transaction_id(1)
+//!     .seoj([0x05, 0xFF, 0x01])
+//!     .deoj([0x0E, 0xF0, 0x01])
+//!     .esv(ServiceCode::Get)
+//!     .props(props!([0x80, []]))
+//!     .build();
+//! socket.send_multicast(&packet).unwrap();
+//! let (response, from) = socket.recv_from().unwrap();
+//! ```
+//!
+//! The `async` feature adds [`AsyncElSocket`] on tokio, [`ElCodec`] for `tokio-util`
+//! [`ElClient`] awaiting the responses to requests, discovering nodes and subscribing to
+//! their notifications,
+//! and [`ElServer`] answering the requests to its objects and announcing their changes.
+
+#[cfg(feature = "async")]
+mod announce;
+#[cfg(feature = "async")]
+mod async_socket;
+#[cfg(feature = "async")]
+mod client;
+#[cfg(feature = "async")]
+mod codec;
+#[cfg(feature = "async")]
+mod discovery;
+#[cfg(feature = "async")]
+mod node_profile;
+#[cfg(feature = "async")]
+mod server;
+#[cfg(feature = "async")]
+mod subscription;
+
+#[cfg(feature = "async")]
+pub use announce::Announcement;
+#[cfg(feature = "async")]
+pub use async_socket::AsyncElSocket;
+#[cfg(feature = "async")]
+pub use client::{ElClient, ElClientBuilder, PropertyResult, Response};
+#[cfg(feature = "async")]
+pub use codec::ElCodec;
+#[cfg(feature = "async")]
+pub use discovery::{Node, NodeObject};
+#[cfg(feature = "async")]
+pub use server::{DeviceObject, ElServer};
+#[cfg(feature = "async")]
+pub use subscription::{Notification, NotificationFilter, NotifiedProperty, Subscription};
+
+use crate::{ElPacket, Result};
+use socket2::{Domain, Protocol, Socket, Type};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6, UdpSocket};
+use std::time::Duration;
+
+/// UDP port of ECHONET Lite.
+pub const EL_PORT: u16 = 3610;
+/// IPv4 multicast address of ECHONET Lite.
+pub const EL_MULTICAST_ADDR_V4: Ipv4Addr = Ipv4Addr::new(224, 0, 23, 0);
+/// IPv6 multicast address of ECHONET Lite, all nodes on the link.
+pub const EL_MULTICAST_ADDR_V6: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1);
+
+/// Largest UDP payload.
+const MAX_PACKET_SIZE: usize = 65_507;
+
+/// IP versions of an [`ElSocket`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpFamily {
+    V4,
+    V6,
+    /// Both IPv4 and IPv6 with an IPv6 socket accepting IPv4 as well.
+    ///
+    /// The OS must support IPv4 multicast options on IPv6 sockets, e.g. Linux.
+    DualStack,
+}
+
+/// A UDP socket sending and receiving ECHONET Lite packets.
+///
+/// IPv4 addresses are used as they are on dual-stack sockets; the IPv4-mapped IPv6 addresses
+/// never appear in the API, so the same code works on every [`IpFamily`].
+#[derive(Debug)]
+pub struct ElSocket {
+    socket: UdpSocket,
+    family: IpFamily,
+    multicast: Vec<SocketAddr>,
+}
+
+impl ElSocket {
+    /// Binds port 3610 and joins the IPv4 multicast group on the interface with address `interface`.
+    ///
+    /// `Ipv4Addr::UNSPECIFIED` lets the OS choose the interface.
+    pub fn bind(interface: Ipv4Addr) -> Result<Self> {
+        ElSocketBuilder::new().interface(interface).bind()
+    }
+
+    /// Binds port 3610 and joins the IPv6 multicast group on the interface with index `interface`.
+    ///
+    /// 0 lets the OS choose the interface.
+    pub fn bind_v6(interface: u32) -> Result<Self> {
+        ElSocketBuilder::new()
+            .family(IpFamily::V6)
+            .interface_v6(interface)
+            .bind()
+    }
+
+    /// Binds port 3610 and joins both the IPv4 and IPv6 multicast groups.
+    pub fn bind_dual_stack(interface: Ipv4Addr, interface_v6: u32) -> Result<Self> {
+        ElSocketBuilder::new()
+            .family(IpFamily::DualStack)
+            .interface(interface)
+            .interface_v6(interface_v6)
+            .bind()
+    }
+
+    /// Sends `packet` to `addr`.
+    pub fn send_to(&self, packet: &ElPacket, addr: SocketAddr) -> Result<()> {
+        let bytes = packet.serialize()?;
+        self.socket.send_to(&bytes, map(self.family, addr))?;
+        Ok(())
+    }
+
+    /// Sends `packet` to all the nodes in the multicast groups.
+    pub fn send_multicast(&self, packet: &ElPacket) -> Result<()> {
+        for addr in &self.multicast {
+            self.send_to(packet, *addr)?;
+        }
+        Ok(())
+    }
+
+    /// Receives a packet and the address of its sender.
+    ///
+    /// Datagrams which are not ECHONET Lite packets are ignored.
+    /// Returns an error satisfying [`ErrorKind::is_timeout`](crate::ErrorKind::is_timeout)
+    /// if nothing is received within the read timeout.
+    pub fn recv_from(&self) -> Result<(ElPacket, SocketAddr)> {
+        let mut buffer = [0u8; MAX_PACKET_SIZE];
+        loop {
+            let (len, addr) = self.socket.recv_from(&mut buffer)?;
+            if let Ok((_, packet)) = ElPacket::from_bytes(&buffer[..len]) {
+                return Ok((packet, unmap(addr)));
+            }
+        }
+    }
+
+    /// Sets the timeout of [`recv_from`](Self::recv_from). `None` blocks forever.
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()> {
+        self.socket.set_read_timeout(timeout)?;
+        Ok(())
+    }
+
+    /// Returns the address the socket is bound to.
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    pub fn family(&self) -> IpFamily {
+        self.family
+    }
+
+    /// Returns the destinations of [`send_multicast`](Self::send_multicast).
+    pub fn multicast_addrs(&self) -> &[SocketAddr] {
+        &self.multicast
+    }
+}
+
+/// Converts an IPv4 address into the IPv4-mapped IPv6 address on dual-stack sockets.
+fn map(family: IpFamily, addr: SocketAddr) -> SocketAddr {
+    match addr {
+        SocketAddr::V4(v4) if family == IpFamily::DualStack => {
+            SocketAddrV6::new(v4.ip().to_ipv6_mapped(), v4.port(), 0, 0).into()
+        }
+        addr => addr,
+    }
+}
+
+/// Converts an IPv4-mapped IPv6 address into the IPv4 address.
+fn unmap(addr: SocketAddr) -> SocketAddr {
+    match addr {
+        SocketAddr::V6(v6) => match v6.ip().to_ipv4_mapped() {
+            Some(ip) => SocketAddrV4::new(ip, v6.port()).into(),
+            None => addr,
+        },
+        addr => addr,
+    }
+}
+
+/// Configures and binds an [`ElSocket`].
+///
+/// # Examples
+///
+/// Sockets on the loopback interface without multicast, e.g. for tests.
+///
+/// ```
+/// use echonet_lite::transport::ElSocketBuilder;
+///
+/// let socket = ElSocketBuilder::new()
+///     .interface([127, 0, 0, 1].into())
+///     .port(0)
+///     .multicast(false)
+///     .bind()
+///     .unwrap();
+/// assert!(socket.local_addr().unwrap().ip().is_loopback());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElSocketBuilder {
+    family: IpFamily,
+    interface: Ipv4Addr,
+    interface_v6: u32,
+    port: u16,
+    multicast: bool,
+    multicast_loop: bool,
+}
+
+impl Default for ElSocketBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElSocketBuilder {
+    pub fn new() -> Self {
+        Self {
+            family: IpFamily::V4,
+            interface: Ipv4Addr::UNSPECIFIED,
+            interface_v6: 0,
+            port: EL_PORT,
+            multicast: true,
+            multicast_loop: false,
+        }
+    }
+
+    /// IP versions to use, IPv4 by default.
+    pub fn family(mut self, family: IpFamily) -> Self {
+        self.family = family;
+        self
+    }
+
+    /// Address of the IPv4 interface to use. The default lets the OS choose.
+    ///
+    /// Without multicast, IPv4 sockets are bound to this address.
+    pub fn interface(mut self, interface: Ipv4Addr) -> Self {
+        self.interface = interface;
+        self
+    }
+
+    /// Index of the IPv6 interface to use. The default 0 lets the OS choose.
+    pub fn interface_v6(mut self, interface: u32) -> Self {
+        self.interface_v6 = interface;
+        self
+    }
+
+    /// Port to bind, 3610 by default. 0 lets the OS choose a free port.
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Whether to join the multicast groups 224.0.23.0 and ff02::1, true by default.
+    pub fn multicast(mut self, multicast: bool) -> Self {
+        self.multicast = multicast;
+        self
+    }
+
+    /// Whether to receive own multicast packets, false by default.
+    pub fn multicast_loop(mut self, multicast_loop: bool) -> Self {
+        self.multicast_loop = multicast_loop;
+        self
+    }
+
+    pub fn bind(self) -> Result<ElSocket> {
+        let (socket, multicast) = self.socket()?;
+        Ok(ElSocket {
+            socket: socket.into(),
+            family: self.family,
+            multicast,
+        })
+    }
+
+    /// Binds an [`AsyncElSocket`]. Must be called within a tokio runtime.
+    #[cfg(feature = "async")]
+    pub fn bind_async(self) -> Result<AsyncElSocket> {
+        let (socket, multicast) = self.socket()?;
+        socket.set_nonblocking(true)?;
+        let socket = tokio::net::UdpSocket::from_std(socket.into())?;
+        Ok(AsyncElSocket::new(socket, self.family, multicast))
+    }
+
+    /// Creates the socket and returns it with the multicast destinations.
+    fn socket(&self) -> Result<(Socket, Vec<SocketAddr>)> {
+        let domain = match self.family {
+            IpFamily::V4 => Domain::IPV4,
+            IpFamily::V6 | IpFamily::DualStack => Domain::IPV6,
+        };
+        let socket = Socket::new(domain, Type::DGRAM, Some(Protocol::UDP))?;
+        // other ECHONET Lite applications on the same host may use the port as well
+        socket.set_reuse_address(true)?;
+
+        let mut multicast = Vec::new();
+        if self.family == IpFamily::V4 {
+            // sockets bound to a unicast address do not receive multicast packets
+            let addr = if self.multicast {
+                Ipv4Addr::UNSPECIFIED
+            } else {
+                self.interface
+            };
+            socket.bind(&SocketAddrV4::new(addr, self.port).into())?;
+        } else {
+            socket.set_only_v6(self.family == IpFamily::V6)?;
+            socket.bind(&SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, self.port, 0, 0).into())?;
+        }
+        let port = socket
+            .local_addr()?
+            .as_socket()
+            .map_or(self.port, |addr| addr.port());
+
+        if self.multicast && self.family != IpFamily::V6 {
+            socket.join_multicast_v4(&EL_MULTICAST_ADDR_V4, &self.interface)?;
+            socket.set_multicast_if_v4(&self.interface)?;
+            socket.set_multicast_loop_v4(self.multicast_loop)?;
+            multicast.push(SocketAddrV4::new(EL_MULTICAST_ADDR_V4, port).into());
+        }
+        if self.multicast && self.family != IpFamily::V4 {
+            socket.join_multicast_v6(&EL_MULTICAST_ADDR_V6, self.interface_v6)?;
+            socket.set_multicast_if_v6(self.interface_v6)?;
+            socket.set_multicast_loop_v6(self.multicast_loop)?;
+            // link-local multicast needs the scope
+            let addr = SocketAddrV6::new(EL_MULTICAST_ADDR_V6, port, 0, self.interface_v6);
+            multicast.push(addr.into());
+        }
+        Ok((socket, multicast))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{props, Edt, ElPacketBuilder, Properties, Property, ServiceCode};
+    use std::net::IpAddr;
+
+    fn loopback(family: IpFamily) -> ElSocket {
+        let socket = ElSocketBuilder::new()
+            .family(family)
+            .interface(Ipv4Addr::LOCALHOST)
+            .port(0)
+            .multicast(false)
+            .bind()
+            .unwrap();
+        socket
+            .set_read_timeout(Some(Duration::from_millis(500)))
+            .unwrap();
+        socket
+    }
+
+    fn packet() -> ElPacket {
+        ElPacketBuilder::new()
+            .transaction_id(1)
+            .seoj([0x05, 0xFF, 0x01])
+            .deoj([0x01, 0x30, 0x01])
+            .esv(ServiceCode::Get)
+            .props(props!([0x80, []]))
+            .build()
+    }
+
+    /// Address of `socket` on the loopback interface of `ip`.
+    fn loopback_addr(socket: &ElSocket, ip: IpAddr) -> SocketAddr {
+        SocketAddr::new(ip, socket.local_addr().unwrap().port())
+    }
+
+    #[test]
+    fn send_and_receive_on_loopback() {
+        let controller = loopback(IpFamily::V4);
+        let device = loopback(IpFamily::V4);
+        let packet = packet();
+
+        // junk and the arbitrary message format are skipped
+        controller
+            .socket
+            .send_to(&[0xFF, 0x00], device.local_addr().unwrap())
+            .unwrap();
+        let mut arbitrary = packet.serialize().unwrap();
+        arbitrary[1] = 0x82;
+        controller
+            .socket
+            .send_to(&arbitrary, device.local_addr().unwrap())
+            .unwrap();
+        controller
+            .send_to(&packet, device.local_addr().unwrap())
+            .unwrap();
+        let (received, from) = device.recv_from().unwrap();
+        assert_eq!(received, packet);
+        assert_eq!(from, controller.local_addr().unwrap());
+
+        let err = device.recv_from().unwrap_err();
+        assert!(err.is_timeout(), "{err}");
+    }
+
+    #[test]
+    fn ipv6_and_dual_stack_on_loopback() {
+        let v4 = loopback(IpFamily::V4);
+        let v6 = loopback(IpFamily::V6);
+        let dual = loopback(IpFamily::DualStack);
+        let packet = packet();
+
+        v6.send_to(&packet, loopback_addr(&dual, Ipv6Addr::LOCALHOST.into()))
+            .unwrap();
+        let (received, from) = dual.recv_from().unwrap();
+        assert_eq!(received, packet);
+        assert_eq!(from, loopback_addr(&v6, Ipv6Addr::LOCALHOST.into()));
+
+        // IPv4 peers see IPv4 addresses on both sides
+        v4.send_to(&packet, loopback_addr(&dual, Ipv4Addr::LOCALHOST.into()))
+            .unwrap();
+        let (_, from) = dual.recv_from().unwrap();
+        assert_eq!(from, v4.local_addr().unwrap());
+        dual.send_to(&packet, from).unwrap();
+        let (_, from) = v4.recv_from().unwrap();
+        assert_eq!(from, loopback_addr(&dual, Ipv4Addr::LOCALHOST.into()));
+    }
+}
@@ -0,0 +1,113 @@
+use super::DeviceObject;
+use crate::object::{encode_object_list, Access, AccessRule};
+use crate::{EchonetObject, Edt, ElPacket, ElPacketBuilder, Properties, Property, ServiceCode};
+
+/// The node profile object of every node.
+pub(super) const NODE_PROFILE: [u8; 3] = [0x0E, 0xF0, 0x01];
+
+const OPERATION_STATUS: u8 = 0x80;
+const VERSION: u8 = 0x82;
+pub(super) const IDENTIFICATION: u8 = 0x83;
+pub(super) const MANUFACTURER: u8 = 0x8A;
+const INSTANCE_COUNT: u8 = 0xD3;
+const CLASS_COUNT: u8 = 0xD4;
+const INSTANCE_LIST_NOTIFICATION: u8 = 0xD5;
+/// Self-node instance list S.
+pub(super) const INSTANCE_LIST: u8 = 0xD6;
+const CLASS_LIST: u8 = 0xD7;
+
+/// Version 1.13 of the specification with the specified message format.
+const VERSION_1_13: [u8; 4] = [0x01, 0x0D, 0x01, 0x00];
+/// Manufacturer code for experimental use.
+pub(super) const EXPERIMENTAL_MANUFACTURER: [u8; 3] = [0xFF, 0xFF, 0xFF];
+/// Upper limits of the lists in a property.
+const MAX_INSTANCES: usize = 84;
+const MAX_CLASSES: usize = 8;
+
+const GET: AccessRule = AccessRule::new(
+    Access::Required,
+    Access::NotApplicable,
+    Access::NotApplicable,
+);
+const GET_ANNO: AccessRule =
+    AccessRule::new(Access::Required, Access::NotApplicable, Access::Required);
+const ANNO: AccessRule = AccessRule::new(
+    Access::NotApplicable,
+    Access::NotApplicable,
+    Access::Required,
+);
+
+/// Creates the node profile without any device objects.
+pub(super) fn node_profile(manufacturer: [u8; 3], unique_id: [u8; 13]) -> DeviceObject {
+    let mut profile = DeviceObject::new(NODE_PROFILE.into())
+        .property(OPERATION_STATUS, [0x30], GET_ANNO)
+        .property(VERSION, VERSION_1_13, GET)
+        .property(IDENTIFICATION, [], GET)
+        .property(MANUFACTURER, [], GET)
+        .property(INSTANCE_COUNT, [], GET)
+        .property(CLASS_COUNT, [], GET)
+        .property(INSTANCE_LIST_NOTIFICATION, [], ANNO)
+        .property(INSTANCE_LIST, [], GET)
+        .property(CLASS_LIST, [], GET);
+    set_identification(&mut profile, manufacturer, unique_id);
+    update(&mut profile, &[]);
+    profile
+}
+
+/// Sets the identification number (0x83) and the manufacturer code (0x8A).
+pub(super) fn set_identification(
+    profile: &mut DeviceObject,
+    manufacturer: [u8; 3],
+    unique_id: [u8; 13],
+) {
+    profile.set_value(
+        IDENTIFICATION,
+        [&[0xFE], &manufacturer[..], &unique_id].concat(),
+    );
+    profile.set_value(MANUFACTURER, manufacturer);
+}
+
+/// Derives the numbers and the lists of instances and classes from the objects of the node
+/// in ascending order.
+///
+/// The lists are cut off at their upper limits.
+pub(super) fn update(profile: &mut DeviceObject, objects: &[EchonetObject]) {
+    let devices: Vec<_> = objects
+        .iter()
+        .copied()
+        .filter(|eoj| !eoj.is_node_profile())
+        .collect();
+    let mut classes: Vec<_> = devices.iter().map(|eoj| eoj.class_code()).collect();
+    classes.dedup();
+
+    let instances = encode_object_list(&devices[..devices.len().min(MAX_INSTANCES)]);
+    let mut class_list = vec![classes.len().min(MAX_CLASSES) as u8];
+    for class in classes.iter().take(MAX_CLASSES) {
+        class_list.extend_from_slice(&class.as_bytes());
+    }
+
+    profile.set_value(INSTANCE_COUNT, &(devices.len() as u32).to_be_bytes()[1..]);
+    // including the node profile class
+    profile.set_value(CLASS_COUNT, (classes.len() as u16 + 1).to_be_bytes());
+    profile.set_value(INSTANCE_LIST_NOTIFICATION, instances.clone());
+    profile.set_value(INSTANCE_LIST, instances);
+    profile.set_value(CLASS_LIST, class_list);
+}
+
+/// Creates the instance list notification (0xD5) of the node profile.
+pub(super) fn instance_list_notification(profile: &DeviceObject, tid: u16) -> Option<ElPacket> {
+    let edt = profile.value(INSTANCE_LIST_NOTIFICATION)?;
+    let props: Properties = vec![Property {
+        epc: INSTANCE_LIST_NOTIFICATION,
+        edt: Edt::new(edt),
+    }]
+    .into();
+    let packet = ElPacketBuilder::new()
+        .transaction_id(tid)
+        .seoj(NODE_PROFILE)
+        .deoj(NODE_PROFILE)
+        .esv(ServiceCode::Inf)
+        .props(props)
+        .build();
+    Some(packet)
+}
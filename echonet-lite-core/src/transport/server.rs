@@ -0,0 +1,862 @@
+use super::announce::{Announcement, Announcer};
+use super::node_profile::{self, EXPERIMENTAL_MANUFACTURER, NODE_PROFILE};
+use super::AsyncElSocket;
+use crate::object::{decode_property_map, encode_property_map, AccessRule};
+use crate::{EchonetObject, Edt, ElPacket, Properties, Property, Result, ServiceCode};
+use futures_util::StreamExt;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Mutex;
+use tokio::sync::{mpsc, Notify};
+use tokio::time::Instant;
+
+/// Status change announcement property map.
+const ANNOUNCE_MAP: u8 = 0x9D;
+/// Set property map.
+const SET_MAP: u8 = 0x9E;
+/// Get property map.
+const GET_MAP: u8 = 0x9F;
+
+type GetHandler = Box<dyn FnMut(u8) -> Option<Vec<u8>> + Send>;
+type SetHandler = Box<dyn FnMut(u8, &[u8]) -> bool + Send>;
+
+/// An ECHONET Lite object served by [`ElServer`], with its property values.
+///
+/// The property maps (0x9D, 0x9E and 0x9F) are derived from the access rules of the properties
+/// unless they are added explicitly: 0x9D lists the properties whose `inf` rule is allowed,
+/// 0x9E those whose `set` rule is allowed and 0x9F those whose `get` rule is allowed.
+/// Get, SetGet and InfReq read the properties allowed by the `get` rule.
+///
+/// Changes of the values of the properties in the status change announcement property map
+/// are announced by the server, see [`ElServer::set_announcement`].
+///
+/// # Examples
+///
+/// ```
+/// use echonet_lite::object::{Access, AccessRule};
+/// use echonet_lite::transport::DeviceObject;
+///
+/// let get_set = AccessRule::new(Access::Required, Access::Required, Access::Required);
+/// let get = AccessRule::new(Access::Required, Access::NotApplicable, Access::NotApplicable);
+/// let aircon = DeviceObject::new([0x01, 0x30, 0x01].into())
+///     .property(0x80, [0x31], get_set)
+///     .property(0xB3, [20], get_set)
+///     .property(0xBB, [22], get)
+///     .on_set(|epc, edt| epc != 0xB3 || (16..=30).contains(&edt[0]));
+/// assert_eq!(aircon.value(0x9E), Some([0x02, 0x80, 0xB3].to_vec()));
+/// ```
+pub struct DeviceObject {
+    eoj: EchonetObject,
+    properties: BTreeMap<u8, (Vec<u8>, AccessRule)>,
+    on_get: Option<GetHandler>,
+    on_set: Option<SetHandler>,
+    /// Changed EPCs to be announced.
+    changes: BTreeSet<u8>,
+}
+
+impl fmt::Debug for DeviceObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DeviceObject")
+            .field("eoj", &self.eoj)
+            .field("properties", &self.properties)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DeviceObject {
+    pub fn new(eoj: EchonetObject) -> Self {
+        Self {
+            eoj,
+            properties: BTreeMap::new(),
+            on_get: None,
+            on_set: None,
+            changes: BTreeSet::new(),
+        }
+    }
+
+    /// Adds a property with its initial value.
+    pub fn property(mut self, epc: u8, edt: impl Into<Vec<u8>>, rule: AccessRule) -> Self {
+        self.properties.insert(epc, (edt.into(), rule));
+        self
+    }
+
+    /// Sets the handler reading a property for Get, SetGet and InfReq.
+    ///
+    /// The handler returns the current value, e.g. read from a sensor,
+    /// or `None` to respond with the stored value.
+    pub fn on_get(mut self, handler: impl FnMut(u8) -> Option<Vec<u8>> + Send + 'static) -> Self {
+        self.on_get = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets the handler accepting or rejecting a write of SetI, SetC and SetGet.
+    ///
+    /// The value is stored if the handler returns true.
+    pub fn on_set(mut self, handler: impl FnMut(u8, &[u8]) -> bool + Send + 'static) -> Self {
+        self.on_set = Some(Box::new(handler));
+        self
+    }
+
+    pub fn eoj(&self) -> EchonetObject {
+        self.eoj
+    }
+
+    /// Returns the stored value of `epc`, including the derived property maps.
+    pub fn value(&self, epc: u8) -> Option<Vec<u8>> {
+        match self.properties.get(&epc) {
+            Some((edt, _)) => Some(edt.clone()),
+            None => self.property_map(epc),
+        }
+    }
+
+    /// Updates the stored value of `epc`, e.g. when the device changed its state by itself.
+    ///
+    /// Returns false if the object does not have the property.
+    pub fn set_value(&mut self, epc: u8, edt: impl Into<Vec<u8>>) -> bool {
+        let Some((value, _)) = self.properties.get_mut(&epc) else {
+            return false;
+        };
+        let edt = edt.into();
+        if *value != edt {
+            *value = edt;
+            if self.announces(epc) {
+                self.changes.insert(epc);
+            }
+        }
+        true
+    }
+
+    /// Returns true if `epc` is in the status change announcement property map.
+    fn announces(&self, epc: u8) -> bool {
+        match self.properties.get(&ANNOUNCE_MAP) {
+            Some((map, _)) => decode_property_map(map).is_some_and(|epcs| epcs.contains(&epc)),
+            None => self
+                .properties
+                .get(&epc)
+                .is_some_and(|(_, rule)| rule.inf.is_allowed()),
+        }
+    }
+
+    /// Takes the EPCs changed since the last call.
+    pub(super) fn take_changes(&mut self) -> BTreeSet<u8> {
+        std::mem::take(&mut self.changes)
+    }
+
+    /// Returns the EPCs of the properties whose rules satisfy `allowed`.
+    fn epcs(&self, allowed: impl Fn(&AccessRule) -> bool) -> Vec<u8> {
+        self.properties
+            .iter()
+            .filter(|(_, (_, rule))| allowed(rule))
+            .map(|(epc, _)| *epc)
+            .collect()
+    }
+
+    /// Derives the property map `epc`, if it is not added explicitly.
+    fn property_map(&self, epc: u8) -> Option<Vec<u8>> {
+        let epcs = match epc {
+            ANNOUNCE_MAP => self.epcs(|rule| rule.inf.is_allowed()),
+            SET_MAP => self.epcs(|rule| rule.set.is_allowed()),
+            GET_MAP => {
+                let mut epcs = self.epcs(|rule| rule.get.is_allowed());
+                epcs.extend([ANNOUNCE_MAP, SET_MAP, GET_MAP]);
+                epcs
+            }
+            _ => return None,
+        };
+        Some(encode_property_map(&epcs))
+    }
+
+    /// Reads `epc`, or returns `None` if it is not readable.
+    fn read(&mut self, epc: u8) -> Option<Vec<u8>> {
+        match self.properties.get(&epc) {
+            Some((_, rule)) if !rule.get.is_allowed() => None,
+            Some((edt, _)) => self
+                .on_get
+                .as_mut()
+                .and_then(|handler| handler(epc))
+                .or_else(|| Some(edt.clone())),
+            None => self.property_map(epc),
+        }
+    }
+
+    /// Writes `edt` to `epc` and returns true if it is accepted.
+    fn write(&mut self, epc: u8, edt: &[u8]) -> bool {
+        let writable = self
+            .properties
+            .get(&epc)
+            .is_some_and(|(_, rule)| rule.set.is_allowed());
+        let accepted = writable
+            && !edt.is_empty()
+            && self.on_set.as_mut().is_none_or(|handler| handler(epc, edt));
+        if accepted {
+            self.set_value(epc, edt);
+        }
+        accepted
+    }
+
+    fn read_all(&mut self, props: &Properties, ok: &mut bool) -> Properties {
+        let props: Vec<_> = props
+            .iter()
+            .map(|prop| {
+                let edt = self.read(prop.epc).unwrap_or_else(|| {
+                    *ok = false;
+                    Vec::new()
+                });
+                Property {
+                    epc: prop.epc,
+                    edt: Edt::new(edt),
+                }
+            })
+            .collect();
+        props.into()
+    }
+
+    fn write_all(&mut self, props: &Properties, ok: &mut bool) -> Properties {
+        let props: Vec<_> = props
+            .iter()
+            .map(|prop| {
+                if self.write(prop.epc, &prop.edt) {
+                    Property {
+                        epc: prop.epc,
+                        edt: Edt::default(),
+                    }
+                } else {
+                    // rejected writes are echoed back
+                    *ok = false;
+                    prop.clone()
+                }
+            })
+            .collect();
+        props.into()
+    }
+
+    /// Processes `request` and returns the response, if one is needed.
+    fn process(&mut self, request: &ElPacket) -> Option<ElPacket> {
+        let mut ok = true;
+        let mut get_props = Properties::default();
+        let (props, esv, sna) = match request.esv {
+            ServiceCode::SetI => {
+                let props = self.write_all(&request.props, &mut ok);
+                if ok {
+                    return None;
+                }
+                (props, ServiceCode::SetISNA, ServiceCode::SetISNA)
+            }
+            ServiceCode::SetC => (
+                self.write_all(&request.props, &mut ok),
+                ServiceCode::SetRes,
+                ServiceCode::SetCSNA,
+            ),
+            ServiceCode::Get => (
+                self.read_all(&request.props, &mut ok),
+                ServiceCode::GetRes,
+                ServiceCode::GetSNA,
+            ),
+            ServiceCode::InfReq => (
+                self.read_all(&request.props, &mut ok),
+                ServiceCode::Inf,
+                ServiceCode::InfSNA,
+            ),
+            ServiceCode::SetGet => {
+                let props = self.write_all(&request.props, &mut ok);
+                get_props = self.read_all(&request.get_props, &mut ok);
+                (props, ServiceCode::SetGetRes, ServiceCode::SetGetSNA)
+            }
+            // responses and notifications
+            _ => return None,
+        };
+
+        let mut response = request.create_response(if ok { esv } else { sna }, props);
+        // the destination may be all the instances of the class
+        response.seoj = self.eoj;
+        response.get_props = get_props;
+        Some(response)
+    }
+}
+
+/// A node serving ECHONET Lite objects (`async` feature).
+///
+/// Requests are dispatched to the objects by their destination; instance 0 stands for
+/// all the instances of the class. Responses, notifications and requests to objects
+/// which are not served are ignored.
+///
+/// The node profile (0x0EF001) is maintained by the server: the numbers and the lists of
+/// instances and classes (0xD3 to 0xD7) follow the added and removed objects, and the
+/// instance list notification (0xD5) is multicast when [`run`](Self::run) starts and whenever
+/// the objects change. The identification number is set with
+/// [`set_identification`](Self::set_identification).
+///
+/// Changes of the properties in the status change announcement property maps (0x9D),
+/// whether written by requests or with [`set_value`](Self::set_value), are announced by
+/// `run` as configured with [`set_announcement`](Self::set_announcement).
+///
+/// # Examples
+///
+/// ```no_run
+/// use echonet_lite::object::AccessRule;
+/// use echonet_lite::transport::{AsyncElSocket, DeviceObject, ElServer};
+/// use std::net::Ipv4Addr;
+/// use std::sync::Arc;
+///
+/// # async fn serve() -> echonet_lite::Result<()> {
+/// let server = Arc::new(ElServer::new(AsyncElSocket::bind(Ipv4Addr::UNSPECIFIED)?));
+/// server.add(DeviceObject::new([0x01, 0x30, 0x01].into()).property(0x80, [0x31], AccessRule::ANY));
+/// tokio::spawn({
+///     let server = server.clone();
+///     async move { server.run().await }
+/// });
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ElServer {
+    socket: AsyncElSocket,
+    objects: Mutex<BTreeMap<EchonetObject, DeviceObject>>,
+    /// Transaction ID of the next notification.
+    tid: AtomicU16,
+    /// Notifications to be multicast by `run`.
+    outbox: mpsc::UnboundedSender<ElPacket>,
+    outbox_receiver: tokio::sync::Mutex<mpsc::UnboundedReceiver<ElPacket>>,
+    announcer: Mutex<Announcer>,
+    /// Wakes `run` up when an announcement is scheduled.
+    wake: Notify,
+}
+
+impl ElServer {
+    /// Creates a server with only the node profile.
+    pub fn new(socket: AsyncElSocket) -> Self {
+        let profile = node_profile::node_profile(EXPERIMENTAL_MANUFACTURER, [0; 13]);
+        let (outbox, outbox_receiver) = mpsc::unbounded_channel();
+        Self {
+            socket,
+            objects: Mutex::new(BTreeMap::from([(profile.eoj, profile)])),
+            tid: AtomicU16::new(1),
+            outbox,
+            outbox_receiver: tokio::sync::Mutex::new(outbox_receiver),
+            announcer: Mutex::default(),
+            wake: Notify::new(),
+        }
+    }
+
+    /// Configures the announcements of the property changes, Inf to the multicast groups
+    /// without delay by default.
+    ///
+    /// Changes scheduled already are announced with the new configuration.
+    pub fn set_announcement(&self, announcement: Announcement) {
+        self.announcer.lock().unwrap().set_config(announcement);
+        self.wake.notify_one();
+    }
+
+    /// Sets the identification number (0x83) and the manufacturer code (0x8A) of the node profile.
+    ///
+    /// The default manufacturer code 0xFFFFFF is for experimental use.
+    pub fn set_identification(&self, manufacturer: [u8; 3], unique_id: [u8; 13]) {
+        let mut objects = self.objects.lock().unwrap();
+        if let Some(profile) = objects.get_mut(&NODE_PROFILE.into()) {
+            node_profile::set_identification(profile, manufacturer, unique_id);
+        }
+    }
+
+    /// Adds `object`, replacing the object with the same EOJ.
+    pub fn add(&self, mut object: DeviceObject) {
+        // changes before being served are not announced
+        object.take_changes();
+        let mut objects = self.objects.lock().unwrap();
+        objects.insert(object.eoj, object);
+        self.objects_changed(&mut objects);
+    }
+
+    /// Removes the object `eoj`.
+    pub fn remove(&self, eoj: EchonetObject) -> Option<DeviceObject> {
+        let mut objects = self.objects.lock().unwrap();
+        let removed = objects.remove(&eoj)?;
+        self.objects_changed(&mut objects);
+        Some(removed)
+    }
+
+    /// Updates the node profile and announces the new instance list.
+    fn objects_changed(&self, objects: &mut BTreeMap<EchonetObject, DeviceObject>) {
+        let eojs: Vec<_> = objects.keys().copied().collect();
+        if let Some(profile) = objects.get_mut(&NODE_PROFILE.into()) {
+            node_profile::update(profile, &eojs);
+            // announced by the instance list notification below
+            profile.take_changes();
+            if let Some(packet) = node_profile::instance_list_notification(profile, self.next_tid())
+            {
+                // the receiver lives as long as the server
+                let _ = self.outbox.send(packet);
+            }
+        }
+    }
+
+    fn next_tid(&self) -> u16 {
+        self.tid.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Schedules the announcement of the changes of `object`.
+    fn announce_changes(&self, object: &mut DeviceObject) {
+        let changes = object.take_changes();
+        if !changes.is_empty() {
+            let mut announcer = self.announcer.lock().unwrap();
+            announcer.changed(object.eoj, &changes, Instant::now());
+            self.wake.notify_one();
+        }
+    }
+
+    /// Returns the EOJs of the objects in ascending order, including the node profile.
+    pub fn objects(&self) -> Vec<EchonetObject> {
+        self.objects.lock().unwrap().keys().copied().collect()
+    }
+
+    /// Returns the stored value of `epc` of the object `eoj`.
+    pub fn value(&self, eoj: EchonetObject, epc: u8) -> Option<Vec<u8>> {
+        self.objects.lock().unwrap().get(&eoj)?.value(epc)
+    }
+
+    /// Updates the stored value of `epc` of the object `eoj`, announcing the change if the
+    /// property is in the status change announcement property map.
+    ///
+    /// Returns false if there is no such object or property.
+    pub fn set_value(&self, eoj: EchonetObject, epc: u8, edt: impl Into<Vec<u8>>) -> bool {
+        let mut objects = self.objects.lock().unwrap();
+        let Some(object) = objects.get_mut(&eoj) else {
+            return false;
+        };
+        let set = object.set_value(epc, edt);
+        self.announce_changes(object);
+        set
+    }
+
+    /// Processes `request` and returns the responses of the objects.
+    ///
+    /// The handlers of the objects are called while the objects are locked,
+    /// so they must not call the server.
+    pub fn handle(&self, request: &ElPacket) -> Vec<ElPacket> {
+        let deoj = request.deoj;
+        let mut objects = self.objects.lock().unwrap();
+        objects
+            .values_mut()
+            .filter(|object| {
+                let eoj = object.eoj;
+                eoj.class_code() == deoj.class_code()
+                    && (deoj.instance() == 0 || deoj.instance() == eoj.instance())
+            })
+            .filter_map(|object| {
+                let response = object.process(request);
+                self.announce_changes(object);
+                response
+            })
+            .collect()
+    }
+
+    /// Receives requests and sends the responses and notifications until the socket is closed.
+    ///
+    /// Failures to send to a node, e.g. an unreachable controller, do not stop the server;
+    /// the packet is dropped as if it was lost on the network.
+    ///
+    /// Only one `run` is active at a time; another call waits for it to finish.
+    pub async fn run(&self) -> Result<()> {
+        let mut outbox = self.outbox_receiver.lock().await;
+        // the announcement at startup supersedes the ones queued before
+        while outbox.try_recv().is_ok() {}
+        let startup = {
+            let objects = self.objects.lock().unwrap();
+            let profile = objects.get(&NODE_PROFILE.into());
+            profile.and_then(|profile| {
+                node_profile::instance_list_notification(profile, self.next_tid())
+            })
+        };
+        if let Some(packet) = startup {
+            let _ = self.socket.send_multicast(&packet).await;
+        }
+
+        let mut packets = self.socket.stream();
+        loop {
+            let deadline = self.announcer.lock().unwrap().deadline();
+            let announce = async {
+                match deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline).await,
+                    None => std::future::pending().await,
+                }
+            };
+            tokio::select! {
+                received = packets.next() => match received {
+                    Some(Ok((from, request))) => {
+                        if request.esv == ServiceCode::InfCRes {
+                            self.announcer.lock().unwrap().acked(&request, from);
+                        }
+                        for response in self.handle(&request) {
+                            let _ = self.socket.send_to(&response, from).await;
+                        }
+                    }
+                    // e.g. ICMP port unreachable of a previous response
+                    Some(Err(_)) => continue,
+                    None => return Ok(()),
+                },
+                Some(packet) = outbox.recv() => {
+                    let _ = self.socket.send_multicast(&packet).await;
+                }
+                // rescheduled
+                _ = self.wake.notified() => {}
+                _ = announce => self.announce().await,
+            }
+        }
+    }
+
+    /// Sends the announcements which are due and retransmits the unacknowledged InfC.
+    async fn announce(&self) {
+        let now = Instant::now();
+        let due = self.announcer.lock().unwrap().take_due(now);
+        let mut packets = Vec::new();
+        for (eoj, epcs) in due {
+            let props: Vec<_> = {
+                let objects = self.objects.lock().unwrap();
+                // removed in the meantime
+                let Some(object) = objects.get(&eoj) else {
+                    continue;
+                };
+                epcs.into_iter()
+                    .filter_map(|epc| {
+                        Some(Property {
+                            epc,
+                            edt: Edt::new(object.value(epc)?),
+                        })
+                    })
+                    .collect()
+            };
+            let mut announcer = self.announcer.lock().unwrap();
+            packets.extend(announcer.packets(eoj, props.into(), || self.next_tid(), now));
+        }
+        let retransmissions = self.announcer.lock().unwrap().retransmissions(now);
+        packets.extend(
+            retransmissions
+                .into_iter()
+                .map(|(packet, to)| (packet, Some(to))),
+        );
+
+        for (packet, to) in packets {
+            // unacknowledged InfC is retransmitted anyway
+            let _ = match to {
+                Some(to) => self.socket.send_to(&packet, to).await,
+                None => self.socket.send_multicast(&packet).await,
+            };
+        }
+    }
+
+    /// Returns the socket, e.g. to send notifications.
+    pub fn socket(&self) -> &AsyncElSocket {
+        &self.socket
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::object::{Access, ClassCode};
+    use crate::transport::{AsyncElSocket, ElClientBuilder, ElSocketBuilder, PropertyResult};
+    use crate::{bulk_read, prop, props, ElPacketBuilder};
+    use std::net::Ipv4Addr;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    const GET_SET: AccessRule =
+        AccessRule::new(Access::Required, Access::Required, Access::Required);
+    const GET: AccessRule = AccessRule::new(
+        Access::Required,
+        Access::NotApplicable,
+        Access::NotApplicable,
+    );
+
+    fn loopback() -> AsyncElSocket {
+        ElSocketBuilder::new()
+            .interface(Ipv4Addr::LOCALHOST)
+            .port(0)
+            .multicast(false)
+            .bind_async()
+            .unwrap()
+    }
+
+    fn server() -> ElServer {
+        let server = ElServer::new(loopback());
+        for instance in [1, 2] {
+            server.add(
+                DeviceObject::new([0x01, 0x30, instance].into())
+                    .property(0x80, [0x31], GET_SET)
+                    .property(0xB3, [20], GET_SET)
+                    .property(0xBB, [22], GET)
+                    .on_set(|epc, edt| epc != 0xB3 || (16..=30).contains(&edt[0])),
+            );
+        }
+        server
+    }
+
+    fn request(instance: u8, esv: ServiceCode, props: Properties) -> ElPacket {
+        ElPacketBuilder::new()
+            .transaction_id(7)
+            .seoj([0x05, 0xFF, 0x01])
+            .deoj([0x01, 0x30, instance])
+            .esv(esv)
+            .props(props)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn get_and_set() {
+        let server = server();
+
+        let responses = server.handle(&request(1, ServiceCode::Get, bulk_read!(0x80, 0xE0)));
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].esv, ServiceCode::GetSNA);
+        assert_eq!(responses[0].transaction_id(), 7);
+        assert_eq!(responses[0].props, props!([0x80, [0x31]], [0xE0, []]));
+
+        // rejected writes are echoed back
+        let set = props!([0x80, [0x30]], [0xB3, [40]], [0xBB, [10]]);
+        let responses = server.handle(&request(1, ServiceCode::SetC, set));
+        assert_eq!(responses[0].esv, ServiceCode::SetCSNA);
+        assert_eq!(
+            responses[0].props,
+            props!([0x80, []], [0xB3, [40]], [0xBB, [10]])
+        );
+        let eoj = [0x01, 0x30, 0x01].into();
+        assert_eq!(server.value(eoj, 0x80).unwrap(), [0x30]);
+        assert_eq!(server.value(eoj, 0xB3).unwrap(), [20]);
+
+        // SetI is answered only if it fails
+        let responses = server.handle(&request(1, ServiceCode::SetI, props!([0xB3, [25]])));
+        assert!(responses.is_empty());
+        let responses = server.handle(&request(1, ServiceCode::SetI, props!([0xB3, []])));
+        assert_eq!(responses[0].esv, ServiceCode::SetISNA);
+
+        // responses and unknown objects are ignored
+        assert!(server
+            .handle(&request(1, ServiceCode::GetRes, props!([0x80, [0x30]])))
+            .is_empty());
+        assert!(server
+            .handle(&request(3, ServiceCode::Get, bulk_read!(0x80)))
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn wildcard_set_get_and_maps() {
+        let server = server();
+
+        let mut set_get = request(0, ServiceCode::SetGet, props!([0x80, [0x30]]));
+        set_get.get_props = bulk_read!(0x80, 0x9E);
+        let responses = server.handle(&set_get);
+        assert_eq!(responses.len(), 2);
+        for (instance, response) in [1, 2].into_iter().zip(&responses) {
+            assert_eq!(response.esv, ServiceCode::SetGetRes);
+            assert_eq!(response.seoj, [0x01, 0x30, instance].into());
+            assert_eq!(response.props, props!([0x80, []]));
+            assert_eq!(
+                response.get_props,
+                props!([0x80, [0x30]], [0x9E, [0x02, 0x80, 0xB3]])
+            );
+        }
+
+        // InfReq reads the properties readable by Get, announced or not
+        let responses = server.handle(&request(2, ServiceCode::InfReq, bulk_read!(0x9F, 0xBB)));
+        assert_eq!(responses[0].esv, ServiceCode::Inf);
+        assert_eq!(
+            responses[0].props,
+            props!(
+                [0x9F, [0x06, 0x80, 0x9D, 0x9E, 0x9F, 0xB3, 0xBB]],
+                [0xBB, [22]]
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn serve_on_loopback() {
+        let server = Arc::new(server());
+        let to = server.socket().local_addr().unwrap();
+        let runner = tokio::spawn({
+            let server = server.clone();
+            async move { server.run().await }
+        });
+
+        let client = ElClientBuilder::new()
+            .timeout(Duration::from_millis(200))
+            .build(loopback());
+        let eoj = [0x01, 0x30, 0x02].into();
+        let response = client.set(to, eoj, props!([0xB3, [26]])).await.unwrap();
+        assert!(response.is_success());
+        let response = client.get(to, eoj, &[0xB3]).await.unwrap();
+        assert_eq!(
+            response.results(),
+            [PropertyResult::Read(prop!(0xB3, [26]))]
+        );
+
+        server.set_identification([0x00, 0x00, 0x77], [0x01; 13]);
+        let node = client.node(to).await.unwrap();
+        let profile = node.profile().unwrap();
+        assert_eq!(profile.manufacturer, Some([0x00, 0x00, 0x77]));
+        assert_eq!(
+            profile.identification.as_ref().unwrap()[..4],
+            [0xFE, 0x00, 0x00, 0x77]
+        );
+        assert!(profile.announce.contains(&0xD5));
+        let devices: Vec<_> = node.devices().map(|object| object.eoj).collect();
+        assert_eq!(devices, server.objects()[..2]);
+        runner.abort();
+    }
+
+    #[tokio::test]
+    async fn node_profile() {
+        let server = server();
+        let mut outbox = server.outbox_receiver.lock().await;
+        let get = |epcs: Properties| {
+            let mut request = request(1, ServiceCode::Get, epcs);
+            request.deoj = NODE_PROFILE.into();
+            server.handle(&request).remove(0).props
+        };
+
+        assert_eq!(
+            get(bulk_read!(0xD3, 0xD4, 0xD6, 0xD7)),
+            props!(
+                [0xD3, [0x00, 0x00, 0x02]],
+                [0xD4, [0x00, 0x02]],
+                [0xD6, [0x02, 0x01, 0x30, 0x01, 0x01, 0x30, 0x02]],
+                [0xD7, [0x01, 0x01, 0x30]]
+            )
+        );
+        // announced only
+        assert_eq!(get(bulk_read!(0xD5)), props!([0xD5, []]));
+
+        server.add(DeviceObject::new([0x00, 0x11, 0x01].into()));
+        server.remove([0x01, 0x30, 0x01].into());
+        assert_eq!(
+            get(bulk_read!(0xD4, 0xD7)),
+            props!([0xD4, [0x00, 0x03]], [0xD7, [0x02, 0x00, 0x11, 0x01, 0x30]])
+        );
+
+        // the notifications of the two instances in server() are followed by these
+        let notifications: Vec<_> = std::iter::from_fn(|| outbox.try_recv().ok()).collect();
+        assert_eq!(notifications.len(), 4);
+        let last = notifications.last().unwrap();
+        assert_eq!(last.esv, ServiceCode::Inf);
+        assert_eq!(last.seoj, NODE_PROFILE.into());
+        assert_eq!(
+            last.props,
+            props!([0xD5, [0x02, 0x00, 0x11, 0x01, 0x01, 0x30, 0x02]])
+        );
+        assert!(server.remove([0x01, 0x30, 0x01].into()).is_none());
+        assert!(outbox.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn wildcard_request() {
+        let server = Arc::new(server());
+        let to = server.socket().local_addr().unwrap();
+        let runner = tokio::spawn({
+            let server = server.clone();
+            async move { server.run().await }
+        });
+
+        let client = ElClientBuilder::new()
+            .timeout(Duration::from_millis(200))
+            .build(loopback());
+        let get = ElPacketBuilder::new()
+            .seoj([0x05, 0xFF, 0x01])
+            .deoj([0x01, 0x30, 0x00])
+            .esv(ServiceCode::Get)
+            .props(bulk_read!(0x80))
+            .build();
+        let responses = client.request(to, get).await.unwrap();
+        let mut instances: Vec<_> = responses
+            .iter()
+            .map(|response| {
+                assert_eq!(response.packet.esv, ServiceCode::GetRes);
+                response.packet.seoj.instance()
+            })
+            .collect();
+        instances.sort();
+        assert_eq!(instances, [1, 2]);
+
+        // the other methods return the first response
+        let response = client.get(to, [0x01, 0x30, 0x00].into(), &[0x80]).await;
+        assert_eq!(
+            response.unwrap().packet.seoj.class_code(),
+            ClassCode::new(0x01, 0x30)
+        );
+        runner.abort();
+    }
+
+    #[tokio::test]
+    async fn keep_running_after_send_errors() {
+        let server = Arc::new(server());
+        let controller = loopback();
+        // sending to port 0 fails
+        let unreachable = "127.0.0.1:0".parse().unwrap();
+        server.set_announcement(Announcement::infc(vec![
+            unreachable,
+            controller.local_addr().unwrap(),
+        ]));
+        let runner = tokio::spawn({
+            let server = server.clone();
+            async move { server.run().await }
+        });
+
+        server.set_value([0x01, 0x30, 0x01].into(), 0x80, [0x30]);
+        let received = tokio::time::timeout(Duration::from_secs(1), controller.recv_from());
+        let (infc, _) = received.await.unwrap().unwrap();
+        assert_eq!(infc.esv, ServiceCode::InfC);
+        let client = ElClientBuilder::new()
+            .timeout(Duration::from_millis(200))
+            .build(loopback());
+        let to = server.socket().local_addr().unwrap();
+        let response = client.get(to, [0x01, 0x30, 0x01].into(), &[0x80]).await;
+        assert!(response.unwrap().is_success());
+        assert!(!runner.is_finished());
+        runner.abort();
+    }
+
+    #[tokio::test]
+    async fn announce_changes() {
+        let server = Arc::new(server());
+        let controller = loopback();
+        server.set_announcement(
+            Announcement::infc(vec![controller.local_addr().unwrap()])
+                .coalesce(Duration::from_millis(50))
+                .ack_timeout(Duration::from_millis(100))
+                .retries(3),
+        );
+        let runner = tokio::spawn({
+            let server = server.clone();
+            async move { server.run().await }
+        });
+
+        // changes by requests and by the device are announced together
+        let eoj = [0x01, 0x30, 0x01].into();
+        server.handle(&request(1, ServiceCode::SetC, props!([0x80, [0x30]])));
+        server.set_value(eoj, 0xB3, [21]);
+        server.set_value(eoj, 0xBB, [23]);
+        let (infc, from) = controller.recv_from().await.unwrap();
+        assert_eq!(infc.esv, ServiceCode::InfC);
+        assert_eq!(infc.seoj, eoj);
+        assert_eq!(infc.props, props!([0x80, [0x30]], [0xB3, [21]]));
+
+        // retransmitted until acknowledged
+        let (retransmitted, _) = controller.recv_from().await.unwrap();
+        assert_eq!(retransmitted, infc);
+        let ack = infc.create_response(ServiceCode::InfCRes, props!([0x80, []], [0xB3, []]));
+        controller.send_to(&ack, from).await.unwrap();
+
+        // writing the same value is not a change
+        server.set_value(eoj, 0x80, [0x30]);
+        let received = tokio::time::timeout(Duration::from_millis(300), controller.recv_from());
+        assert!(received.await.is_err());
+        runner.abort();
+
+        // an explicit map overrides the access rules
+        let mut object = DeviceObject::new(eoj)
+            .property(0x80, [0x30], GET_SET)
+            .property(0xBB, [20], GET)
+            .property(ANNOUNCE_MAP, encode_property_map(&[0xBB]), GET);
+        object.set_value(0x80, [0x31]);
+        object.set_value(0xBB, [21]);
+        assert_eq!(object.take_changes(), BTreeSet::from([0xBB]));
+    }
+}
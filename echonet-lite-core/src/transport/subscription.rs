@@ -0,0 +1,280 @@
+use crate::object::{ClassRegistry, Value};
+use crate::{EchonetObject, Edt, ElPacket, Properties, Property, ServiceCode};
+use futures_util::Stream;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+
+/// Selects the notifications delivered to a [`Subscription`].
+///
+/// Without any condition, all the Inf and InfC received by the client are delivered.
+///
+/// # Examples
+///
+/// ```
+/// use echonet_lite::transport::NotificationFilter;
+///
+/// // operation status and room temperature of all the air conditioners of a node
+/// let filter = NotificationFilter::new()
+///     .node([192, 168, 1, 20].into())
+///     .eoj([0x01, 0x30, 0x00].into())
+///     .epc(0x80)
+///     .epc(0xBB);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NotificationFilter {
+    node: Option<IpAddr>,
+    eoj: Option<EchonetObject>,
+    epcs: Vec<u8>,
+}
+
+impl NotificationFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only the notifications from the node at `addr`.
+    pub fn node(mut self, addr: IpAddr) -> Self {
+        self.node = Some(addr);
+        self
+    }
+
+    /// Only the notifications from `eoj`; instance 0 stands for all the instances of the class.
+    pub fn eoj(mut self, eoj: EchonetObject) -> Self {
+        self.eoj = Some(eoj);
+        self
+    }
+
+    /// Only the notified properties `epc`, which may be added more than once.
+    pub fn epc(mut self, epc: u8) -> Self {
+        self.epcs.push(epc);
+        self
+    }
+
+    fn matches(&self, from: SocketAddr, seoj: EchonetObject) -> bool {
+        let node = self.node.is_none_or(|node| node == from.ip());
+        let eoj = self.eoj.is_none_or(|eoj| {
+            eoj.class_code() == seoj.class_code()
+                && (eoj.instance() == 0 || eoj.instance() == seoj.instance())
+        });
+        node && eoj
+    }
+
+    fn selects(&self, epc: u8) -> bool {
+        self.epcs.is_empty() || self.epcs.contains(&epc)
+    }
+}
+
+/// A property value notification (Inf or InfC) received by [`ElClient`](super::ElClient).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// Address of the notifying node.
+    pub from: SocketAddr,
+    /// The notifying object.
+    pub eoj: EchonetObject,
+    /// Inf or InfC, which has been acknowledged already.
+    pub esv: ServiceCode,
+    /// The notified properties selected by the filter.
+    pub properties: Vec<NotifiedProperty>,
+}
+
+/// A property of a [`Notification`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotifiedProperty {
+    pub epc: u8,
+    pub edt: Edt,
+    /// The value decoded with the class definition, if the class and the property are known.
+    pub value: Option<Value>,
+}
+
+impl Notification {
+    /// Returns the property `epc`, if it is notified.
+    pub fn property(&self, epc: u8) -> Option<&NotifiedProperty> {
+        self.properties.iter().find(|prop| prop.epc == epc)
+    }
+}
+
+/// Notifications matching a [`NotificationFilter`], created by
+/// [`ElClient::subscribe`](super::ElClient::subscribe).
+///
+/// It is also a [`Stream`] of the notifications. Dropping it ends the subscription.
+#[derive(Debug)]
+pub struct Subscription {
+    receiver: mpsc::UnboundedReceiver<Notification>,
+}
+
+impl Subscription {
+    /// Awaits the next notification, or returns `None` if the client is dropped.
+    pub async fn recv(&mut self) -> Option<Notification> {
+        self.receiver.recv().await
+    }
+}
+
+impl Stream for Subscription {
+    type Item = Notification;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Notification>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// The subscriptions of a client.
+#[derive(Debug, Default)]
+pub(super) struct Subscribers {
+    subscribers: Mutex<Vec<(NotificationFilter, mpsc::UnboundedSender<Notification>)>>,
+}
+
+impl Subscribers {
+    pub(super) fn subscribe(&self, filter: NotificationFilter) -> Subscription {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.subscribers.lock().unwrap().push((filter, sender));
+        Subscription { receiver }
+    }
+
+    /// Delivers `packet` to the matching subscriptions if it is a notification,
+    /// forgetting the dropped ones.
+    pub(super) fn notify(&self, from: SocketAddr, packet: &ElPacket, registry: &ClassRegistry) {
+        if !matches!(packet.esv, ServiceCode::Inf | ServiceCode::InfC) {
+            return;
+        }
+        let class = registry.get(packet.seoj.class_code().as_bytes());
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|(filter, sender)| {
+            if !filter.matches(from, packet.seoj) {
+                return !sender.is_closed();
+            }
+            let properties: Vec<_> = packet
+                .props
+                .iter()
+                .filter(|prop| filter.selects(prop.epc))
+                .map(|prop| NotifiedProperty {
+                    epc: prop.epc,
+                    edt: prop.edt.clone(),
+                    value: class
+                        .and_then(|class| class.property(prop.epc))
+                        .and_then(|def| def.decode(&prop.edt)),
+                })
+                .collect();
+            if properties.is_empty() {
+                return !sender.is_closed();
+            }
+            let notification = Notification {
+                from,
+                eoj: packet.seoj,
+                esv: packet.esv,
+                properties,
+            };
+            sender.send(notification).is_ok()
+        });
+    }
+}
+
+/// Creates the InfCRes acknowledging `packet`, if it is InfC.
+pub(super) fn acknowledgement(packet: &ElPacket) -> Option<ElPacket> {
+    if packet.esv != ServiceCode::InfC {
+        return None;
+    }
+    let props: Vec<_> = packet
+        .props
+        .iter()
+        .map(|prop| Property {
+            epc: prop.epc,
+            edt: Edt::default(),
+        })
+        .collect();
+    Some(packet.create_response(ServiceCode::InfCRes, Properties::from(props)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::transport::{AsyncElSocket, ElClient, ElSocketBuilder};
+    use crate::{props, ElPacketBuilder};
+    use std::net::Ipv4Addr;
+    use std::time::Duration;
+
+    fn loopback() -> AsyncElSocket {
+        ElSocketBuilder::new()
+            .interface(Ipv4Addr::LOCALHOST)
+            .port(0)
+            .multicast(false)
+            .bind_async()
+            .unwrap()
+    }
+
+    fn notification(tid: u16, esv: ServiceCode, seoj: [u8; 3], props: Properties) -> ElPacket {
+        ElPacketBuilder::new()
+            .transaction_id(tid)
+            .seoj(seoj)
+            .deoj([0x05, 0xFF, 0x01])
+            .esv(esv)
+            .props(props)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn subscribe_and_acknowledge() {
+        let client = ElClient::new(loopback());
+        let to = client.socket().local_addr().unwrap();
+        let device = loopback();
+        let aircon = EchonetObject::from([0x01, 0x30, 0x01]);
+        let mut all = client.subscribe(NotificationFilter::new());
+        let mut status = client.subscribe(
+            NotificationFilter::new()
+                .node(Ipv4Addr::LOCALHOST.into())
+                .eoj([0x01, 0x30, 0x00].into())
+                .epc(0x80),
+        );
+
+        // another class and another property are not delivered to `status`
+        let lighting = notification(
+            1,
+            ServiceCode::Inf,
+            [0x02, 0x90, 0x01],
+            props!([0x80, [0x30]]),
+        );
+        device.send_to(&lighting, to).await.unwrap();
+        let temperature = notification(
+            2,
+            ServiceCode::Inf,
+            [0x01, 0x30, 0x01],
+            props!([0xBB, [22]]),
+        );
+        device.send_to(&temperature, to).await.unwrap();
+        let infc = notification(
+            3,
+            ServiceCode::InfC,
+            [0x01, 0x30, 0x01],
+            props!([0x80, [0x31]], [0xBB, [23]]),
+        );
+        device.send_to(&infc, to).await.unwrap();
+
+        let (ack, _) = device.recv_from().await.unwrap();
+        assert_eq!(ack.esv, ServiceCode::InfCRes);
+        assert_eq!(ack.transaction_id(), 3);
+        assert_eq!(ack.seoj, [0x05, 0xFF, 0x01].into());
+        assert_eq!(ack.deoj, aircon);
+        assert_eq!(ack.props, props!([0x80, []], [0xBB, []]));
+
+        let notification = status.recv().await.unwrap();
+        assert_eq!(notification.from.ip(), Ipv4Addr::LOCALHOST);
+        assert_eq!(notification.eoj, aircon);
+        assert_eq!(notification.esv, ServiceCode::InfC);
+        assert_eq!(notification.properties.len(), 1);
+        let prop = notification.property(0x80).unwrap();
+        assert_eq!(&*prop.edt, [0x31]);
+        assert_eq!(prop.value.unwrap().to_string(), "OFF");
+
+        let received: Vec<_> = std::iter::from_fn(|| all.receiver.try_recv().ok()).collect();
+        assert_eq!(received.len(), 3);
+        assert_eq!(received[0].eoj, [0x02, 0x90, 0x01].into());
+        assert!(received[1].property(0xBB).unwrap().value.is_some());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), status.recv())
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! Generates the whole device catalogue of an MRA data directory.
+
+use crate::phfgen::{property_map, release_range};
+use crate::typegen::{byte_list, pascal_ident, snake_case};
+use echonet_lite::mra::{ClassKind, MraClass, MraData};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Class code, constant name and `ElClass` variant of the classes named by the core crate
+/// before the catalogue, which keep their names.
+const ALIASES: &[(&str, &str, &str)] = &[
+    ("0x0130", "HOME_AIR_CONDITIONER", "HomeAC"),
+    ("0x026B", "HP", "Hp"),
+    (
+        "0x0272",
+        "INSTANTANEOUS_WATER_HEATER",
+        "InstantaneousWaterHeater",
+    ),
+    ("0x0279", "HOUSEHOLD_SOLAR_POWER", "Pv"),
+    ("0x027C", "FUEL_CELL", "FuelCell"),
+    ("0x027D", "STORAGE_BATTERY", "Battery"),
+    ("0x027E", "EVPS", "Evps"),
+    ("0x0287", "POWER_DISTRIBUTION_BOARD_METERING", "Metering"),
+    ("0x0288", "SMART_METER", "SmartMeter"),
+    ("0x0290", "GENERAL_LIGHTING", "GeneralLighting"),
+    ("0x0291", "MONO_FUNCTION_LIGHTING", "MonoFunctionLighting"),
+    ("0x02A3", "LIGHTING_SYSTEM", "LightingSystem"),
+    ("0x02A5", "MULTI_INPUT_PCS", "MultiInputPCS"),
+    ("0x05FF", "CONTROLLER", "Controller"),
+];
+
+/// Classes read from `mraData`.
+pub struct Catalogue {
+    pub super_class: MraClass,
+    pub node_profile: MraClass,
+    pub devices: Vec<MraClass>,
+}
+
+impl Catalogue {
+    /// Reads `superClass`, `nodeProfile` and `devices` of `mra_data` and resolves their references.
+    pub fn read(mra_data: &Path) -> anyhow::Result<Self> {
+        let data = MraData::load(mra_data)?;
+        Ok(Self {
+            super_class: data.super_class,
+            node_profile: data.node_profile,
+            devices: data.devices,
+        })
+    }
+
+    /// Node profile and device classes.
+    pub(crate) fn classes(&self) -> impl Iterator<Item = &MraClass> {
+        std::iter::once(&self.node_profile).chain(&self.devices)
+    }
+
+    /// Writes `property_maps.rs` with the release ranges of the classes, `code.rs` and `el_class.rs` into `out_dir`.
+    pub fn write(&self, out_dir: &Path) -> anyhow::Result<()> {
+        fs::create_dir_all(out_dir)?;
+        fs::write(out_dir.join("property_maps.rs"), self.property_maps())?;
+        fs::write(out_dir.join("code.rs"), self.codes())?;
+        fs::write(out_dir.join("el_class.rs"), self.el_class())?;
+        Ok(())
+    }
+
+    fn property_maps(&self) -> String {
+        let mut out = String::new();
+        writeln!(
+            out,
+            "use super::{{Access, AccessRule, LocalizedName, NumberDef, PropertyDef, ReleaseRange}};"
+        )
+        .unwrap();
+        writeln!(out, "use phf::phf_map;").unwrap();
+        writeln!(out).unwrap();
+        for class in std::iter::once(&self.super_class).chain(self.classes()) {
+            let map = format!("{}_CLASS", name(class));
+            writeln!(out, "/// {} ({})", class.class_name.ja, class.class_name.en).unwrap();
+            writeln!(out, "{}", property_map(&map, class)).unwrap();
+            // the super class is a part of the device classes and has no releases of its own
+            if class.kind() != ClassKind::SuperClass {
+                writeln!(
+                    out,
+                    "pub const {}_RELEASES: ReleaseRange = {};\n",
+                    name(class),
+                    release_range(&class.valid_release)
+                )
+                .unwrap();
+            }
+        }
+        out
+    }
+
+    fn codes(&self) -> String {
+        let mut out = String::new();
+        for class in self.classes() {
+            writeln!(out, "/// {} ({})", class.class_name.ja, class.class_name.en).unwrap();
+            writeln!(
+                out,
+                "pub const {}: [u8; 2] = [{}];",
+                name(class),
+                byte_list(&class.eoj, 2)
+            )
+            .unwrap();
+        }
+        out
+    }
+
+    fn el_class(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "use super::code;\n").unwrap();
+        writeln!(out, "/// echonet-lite class representation.").unwrap();
+        writeln!(out, "#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]").unwrap();
+        writeln!(out, "pub enum ElClass {{").unwrap();
+        for class in self.classes() {
+            writeln!(out, "    /// {}", class.class_name.en).unwrap();
+            writeln!(out, "    {},", variant(class)).unwrap();
+        }
+        writeln!(out, "    Unknown([u8; 2]),").unwrap();
+        writeln!(out, "}}\n").unwrap();
+
+        writeln!(out, "impl From<&[u8; 2]> for ElClass {{").unwrap();
+        writeln!(out, "    fn from(value: &[u8; 2]) -> Self {{").unwrap();
+        writeln!(out, "        use code::*;").unwrap();
+        writeln!(out, "        use ElClass::*;").unwrap();
+        writeln!(out, "        match *value {{").unwrap();
+        for class in self.classes() {
+            let (code, variant) = (name(class), variant(class));
+            writeln!(out, "            {code} => {variant},").unwrap();
+        }
+        writeln!(out, "            _ => Unknown(*value),").unwrap();
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+
+        writeln!(out, "impl From<ElClass> for [u8; 2] {{").unwrap();
+        writeln!(out, "    fn from(value: ElClass) -> Self {{").unwrap();
+        writeln!(out, "        use code::*;").unwrap();
+        writeln!(out, "        use ElClass::*;").unwrap();
+        writeln!(out, "        match value {{").unwrap();
+        for class in self.classes() {
+            let (code, variant) = (name(class), variant(class));
+            writeln!(out, "            {variant} => {code},").unwrap();
+        }
+        writeln!(out, "            Unknown(raw) => raw,").unwrap();
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        out
+    }
+}
+
+/// Name of the constants of the class, `SUPER`, `PROFILE` and the aliases as in the core crate.
+fn name(class: &MraClass) -> String {
+    match class.kind() {
+        ClassKind::SuperClass => "SUPER".to_string(),
+        ClassKind::NodeProfile => "PROFILE".to_string(),
+        ClassKind::Device => match alias(class) {
+            Some((_, name, _)) => name.to_string(),
+            None => constant(&class.short_name),
+        },
+    }
+}
+
+/// Name of the `ElClass` variant of the class.
+fn variant(class: &MraClass) -> String {
+    match (class.kind(), alias(class)) {
+        (ClassKind::NodeProfile, _) => "Profile".to_string(),
+        (_, Some((_, _, variant))) => variant.to_string(),
+        _ => pascal_ident(&class.short_name, 'V'),
+    }
+}
+
+fn alias(class: &MraClass) -> Option<&'static (&'static str, &'static str, &'static str)> {
+    ALIASES
+        .iter()
+        .find(|(code, _, _)| code.eq_ignore_ascii_case(&class.eoj))
+}
+
+/// Converts an MRA short name into `SCREAMING_SNAKE_CASE`.
+fn constant(name: &str) -> String {
+    snake_case(name).to_ascii_uppercase()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn catalogue() -> Catalogue {
+        let class = |eoj: &str, short_name: &str, props: &str| -> MraClass {
+            serde_json::from_str(&format!(
+                r#"{{ "eoj": "{eoj}", "validRelease": {{ "from": "A", "to": "latest" }},
+                    "className": {{ "ja": "", "en": "class {eoj}" }},
+                    "shortName": "{short_name}", "elProperties": [{props}] }}"#
+            ))
+            .unwrap()
+        };
+        let prop = r#"{ "epc": "0xE7", "validRelease": { "from": "F", "to": "latest" },
+            "propertyName": { "ja": "瞬時電力計測値", "en": "Measured instantaneous electric power" },
+            "shortName": "instantaneousElectricPower",
+            "accessRule": { "get": "required", "set": "notApplicable", "inf": "optional" },
+            "descriptions": { "ja": "", "en": "" },
+            "data": { "type": "number", "format": "int32", "unit": "W" } }"#;
+        Catalogue {
+            super_class: class("0x0000", "superClass", ""),
+            node_profile: class("0x0EF0", "nodeProfile", ""),
+            devices: vec![
+                class("0x0288", "lvSmartElectricEnergyMeter", prop),
+                class("0x0135", "airCleaner", ""),
+            ],
+        }
+    }
+
+    #[test]
+    fn generated_names() {
+        let catalogue = catalogue();
+
+        let maps = catalogue.property_maps();
+        assert!(maps.contains("pub static SUPER_CLASS: phf::Map<u8, PropertyDef>"));
+        assert!(maps.contains("pub static PROFILE_CLASS: phf::Map<u8, PropertyDef>"));
+        assert!(maps.contains("pub static SMART_METER_CLASS: phf::Map<u8, PropertyDef>"));
+        assert!(maps.contains("pub const SMART_METER_RELEASES: ReleaseRange"));
+        assert!(maps.contains("pub static AIR_CLEANER_CLASS: phf::Map<u8, PropertyDef>"));
+        assert!(maps.contains(
+            ".access(AccessRule::new(Access::Required, Access::NotApplicable, Access::Optional))\
+             .releases(ReleaseRange::since(b'F'))"
+        ));
+        assert!(!maps.contains("LV_SMART"));
+
+        let codes = catalogue.codes();
+        assert!(codes.contains("pub const PROFILE: [u8; 2] = [0x0E, 0xF0];"));
+        assert!(codes.contains("pub const SMART_METER: [u8; 2] = [0x02, 0x88];"));
+        assert!(codes.contains("pub const AIR_CLEANER: [u8; 2] = [0x01, 0x35];"));
+
+        let el_class = catalogue.el_class();
+        assert!(el_class.contains("SMART_METER => SmartMeter,"));
+        assert!(el_class.contains("AIR_CLEANER => AirCleaner,"));
+        assert!(el_class.contains("Profile => PROFILE,"));
+    }
+}
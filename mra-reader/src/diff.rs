@@ -0,0 +1,392 @@
+//! Compares two MRA data directories.
+
+use crate::catalogue::Catalogue;
+use echonet_lite::mra::{AccessRule, MraClass, Property, TypeDef, ValidRelease};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A difference between two MRA releases.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "change", rename_all = "camelCase")]
+pub enum Change {
+    ClassAdded {
+        class: String,
+        name: String,
+    },
+    ClassRemoved {
+        class: String,
+        name: String,
+    },
+    PropertyAdded {
+        class: String,
+        epc: String,
+        name: String,
+    },
+    PropertyRemoved {
+        class: String,
+        epc: String,
+        name: String,
+    },
+    TypeChanged {
+        class: String,
+        epc: String,
+        old: String,
+        new: String,
+    },
+    StateAdded {
+        class: String,
+        epc: String,
+        edt: String,
+        name: String,
+    },
+    StateRemoved {
+        class: String,
+        epc: String,
+        edt: String,
+        name: String,
+    },
+    AccessChanged {
+        class: String,
+        epc: String,
+        old: String,
+        new: String,
+    },
+    ClassReleaseChanged {
+        class: String,
+        old: String,
+        new: String,
+    },
+    ReleaseChanged {
+        class: String,
+        epc: String,
+        old: String,
+        new: String,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::ClassAdded { class, name } => write!(f, "+ class {class} {name}"),
+            Change::ClassRemoved { class, name } => write!(f, "- class {class} {name}"),
+            Change::PropertyAdded { class, epc, name } => {
+                write!(f, "+ property {class} {epc} {name}")
+            }
+            Change::PropertyRemoved { class, epc, name } => {
+                write!(f, "- property {class} {epc} {name}")
+            }
+            Change::TypeChanged {
+                class,
+                epc,
+                old,
+                new,
+            } => write!(f, "~ type {class} {epc}: {old} -> {new}"),
+            Change::StateAdded {
+                class,
+                epc,
+                edt,
+                name,
+            } => write!(f, "+ state {class} {epc} {edt} {name}"),
+            Change::StateRemoved {
+                class,
+                epc,
+                edt,
+                name,
+            } => write!(f, "- state {class} {epc} {edt} {name}"),
+            Change::AccessChanged {
+                class,
+                epc,
+                old,
+                new,
+            } => write!(f, "~ access {class} {epc}: {old} -> {new}"),
+            Change::ClassReleaseChanged { class, old, new } => {
+                write!(f, "~ release {class}: {old} -> {new}")
+            }
+            Change::ReleaseChanged {
+                class,
+                epc,
+                old,
+                new,
+            } => write!(f, "~ release {class} {epc}: {old} -> {new}"),
+        }
+    }
+}
+
+/// Lists the changes of the super class, the node profile and the devices from `old` to `new`.
+pub fn diff(old: &Catalogue, new: &Catalogue) -> Vec<Change> {
+    let classes = |catalogue: &Catalogue| -> BTreeMap<String, MraClass> {
+        std::iter::once(&catalogue.super_class)
+            .chain(catalogue.classes())
+            .map(|class| (class.eoj.to_uppercase().replace("0X", "0x"), class.clone()))
+            .collect()
+    };
+    let old = classes(old);
+    let new = classes(new);
+
+    let mut changes = Vec::new();
+    for (code, class) in &old {
+        match new.get(code) {
+            Some(new_class) => diff_class(code, class, new_class, &mut changes),
+            None => changes.push(Change::ClassRemoved {
+                class: code.clone(),
+                name: class.class_name.en.clone(),
+            }),
+        }
+    }
+    for (code, class) in &new {
+        if !old.contains_key(code) {
+            changes.push(Change::ClassAdded {
+                class: code.clone(),
+                name: class.class_name.en.clone(),
+            });
+        }
+    }
+    changes
+}
+
+fn diff_class(code: &str, old: &MraClass, new: &MraClass, changes: &mut Vec<Change>) {
+    if old.valid_release != new.valid_release {
+        changes.push(Change::ClassReleaseChanged {
+            class: code.to_string(),
+            old: release(&old.valid_release),
+            new: release(&new.valid_release),
+        });
+    }
+    let properties = |class: &MraClass| -> BTreeMap<String, Property> {
+        class
+            .latest_properties()
+            .into_iter()
+            .map(|prop| (prop.epc.to_uppercase().replace("0X", "0x"), prop.clone()))
+            .collect()
+    };
+    let old = properties(old);
+    let new = properties(new);
+
+    for (epc, prop) in &old {
+        let Some(new_prop) = new.get(epc) else {
+            changes.push(Change::PropertyRemoved {
+                class: code.to_string(),
+                epc: epc.clone(),
+                name: prop.name.en.clone(),
+            });
+            continue;
+        };
+
+        match (&prop.data, &new_prop.data) {
+            (TypeDef::Inline(old_def), TypeDef::Inline(new_def))
+                if old_def.size == new_def.size =>
+            {
+                for state in &old_def.enumeration {
+                    if !new_def.enumeration.iter().any(|s| s.edt == state.edt) {
+                        changes.push(Change::StateRemoved {
+                            class: code.to_string(),
+                            epc: epc.clone(),
+                            edt: state.edt.clone(),
+                            name: state.descriptions.en.clone(),
+                        });
+                    }
+                }
+                for state in &new_def.enumeration {
+                    if !old_def.enumeration.iter().any(|s| s.edt == state.edt) {
+                        changes.push(Change::StateAdded {
+                            class: code.to_string(),
+                            epc: epc.clone(),
+                            edt: state.edt.clone(),
+                            name: state.descriptions.en.clone(),
+                        });
+                    }
+                }
+            }
+            (old_data, new_data) if old_data != new_data => {
+                let (old_type, mut new_type) = (summary(old_data), summary(new_data));
+                if old_type == new_type {
+                    new_type.push_str(" (details changed)");
+                }
+                changes.push(Change::TypeChanged {
+                    class: code.to_string(),
+                    epc: epc.clone(),
+                    old: old_type,
+                    new: new_type,
+                });
+            }
+            _ => {}
+        }
+
+        if prop.access_rule != new_prop.access_rule {
+            changes.push(Change::AccessChanged {
+                class: code.to_string(),
+                epc: epc.clone(),
+                old: access(&prop.access_rule),
+                new: access(&new_prop.access_rule),
+            });
+        }
+
+        // e.g. a property retired in the new release
+        if prop.valid_release != new_prop.valid_release {
+            changes.push(Change::ReleaseChanged {
+                class: code.to_string(),
+                epc: epc.clone(),
+                old: release(&prop.valid_release),
+                new: release(&new_prop.valid_release),
+            });
+        }
+    }
+    for (epc, prop) in &new {
+        if !old.contains_key(epc) {
+            changes.push(Change::PropertyAdded {
+                class: code.to_string(),
+                epc: epc.clone(),
+                name: prop.name.en.clone(),
+            });
+        }
+    }
+}
+
+fn access(rule: &AccessRule) -> String {
+    format!("get {}, set {}, inf {}", rule.get, rule.set, rule.inf)
+}
+
+fn release(release: &ValidRelease) -> String {
+    format!("{}..{}", release.from, release.to)
+}
+
+/// Short description of a type, e.g. `number(uint8, 0..100, %)`.
+fn summary(data: &TypeDef) -> String {
+    match data {
+        TypeDef::Ref(def) => def.def.clone(),
+        TypeDef::Inline(def) => format!("state(size {})", def.size),
+        TypeDef::OneOf(def) => {
+            let types: Vec<_> = def.one_of.iter().map(summary).collect();
+            format!("oneOf({})", types.join(" | "))
+        }
+        TypeDef::Object(def) => {
+            let elements: Vec<_> = def
+                .properties
+                .iter()
+                .map(|element| format!("{}: {}", element.short_name, summary(&element.element)))
+                .collect();
+            format!("object {{ {} }}", elements.join(", "))
+        }
+        TypeDef::BitMap(def) => format!("bitmap(size {})", def.size),
+        TypeDef::Array(def) => format!("array({}, max {})", summary(&def.items), def.max_items),
+        TypeDef::Numeric(def) => format!("numericValue(size {})", def.size),
+        TypeDef::Number(def) => {
+            let mut params = vec![def.format.clone()];
+            if def.minimum.is_some() || def.maximum.is_some() {
+                let bound = |value: Option<i64>| value.map(|v| v.to_string()).unwrap_or_default();
+                params.push(format!("{}..{}", bound(def.minimum), bound(def.maximum)));
+            }
+            params.extend(def.unit.clone());
+            params.extend(def.multiple.map(|m| format!("x{m}")));
+            format!("number({})", params.join(", "))
+        }
+        TypeDef::Raw(def) => format!("raw(size {}..{})", def.min_size, def.max_size),
+        TypeDef::Level(def) => format!("level({}, {} levels)", def.base, def.maximum),
+        TypeDef::DateTime(def) => format!("{}(size {})", def.t, def.size),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn catalogue(devices: &str) -> Catalogue {
+        let class = |eoj: &str, props: &str| -> MraClass {
+            serde_json::from_str(&format!(
+                r#"{{ "eoj": "{eoj}", "validRelease": {{ "from": "A", "to": "latest" }},
+                    "className": {{ "ja": "", "en": "class {eoj}" }},
+                    "shortName": "class{eoj}", "elProperties": [{props}] }}"#
+            ))
+            .unwrap()
+        };
+        Catalogue {
+            super_class: class("0x0000", ""),
+            node_profile: class("0x0EF0", ""),
+            devices: vec![class("0x0130", devices)],
+        }
+    }
+
+    fn prop(epc: &str, set: &str, data: &str) -> String {
+        format!(
+            r#"{{ "epc": "{epc}", "validRelease": {{ "from": "A", "to": "latest" }},
+                "propertyName": {{ "ja": "", "en": "prop {epc}" }}, "shortName": "p",
+                "accessRule": {{ "get": "required", "set": "{set}", "inf": "optional" }},
+                "descriptions": {{ "ja": "", "en": "" }}, "data": {data} }}"#
+        )
+    }
+
+    #[test]
+    fn changes() {
+        let state = |edts: &[&str]| {
+            let states: Vec<_> = edts
+                .iter()
+                .map(|edt| {
+                    format!(
+                        r#"{{ "edt": "{edt}", "name": "s", "descriptions": {{ "ja": "", "en": "{edt}" }} }}"#
+                    )
+                })
+                .collect();
+            format!(
+                r#"{{ "type": "state", "size": 1, "enum": [{}] }}"#,
+                states.join(",")
+            )
+        };
+        let old = catalogue(
+            &[
+                prop("0x80", "optional", &state(&["0x30", "0x31"])),
+                prop(
+                    "0xB0",
+                    "optional",
+                    r#"{ "type": "number", "format": "uint8" }"#,
+                ),
+                prop(
+                    "0xB1",
+                    "optional",
+                    r#"{ "type": "number", "format": "uint8" }"#,
+                ),
+            ]
+            .join(","),
+        );
+        let new = catalogue(
+            &[
+                prop("0x80", "required", &state(&["0x30", "0x32"])),
+                prop(
+                    "0xB0",
+                    "optional",
+                    r#"{ "type": "number", "format": "uint16" }"#,
+                )
+                .replace(r#""to": "latest""#, r#""to": "J""#),
+                prop(
+                    "0xB2",
+                    "optional",
+                    r#"{ "type": "number", "format": "uint8" }"#,
+                ),
+            ]
+            .join(","),
+        );
+
+        let mut new = new;
+        new.devices[0].valid_release.to = "K".to_string();
+        let text: Vec<_> = diff(&old, &new).iter().map(ToString::to_string).collect();
+        assert_eq!(
+            text,
+            [
+                "~ release 0x0130: A..latest -> A..K",
+                "- state 0x0130 0x80 0x31 0x31",
+                "+ state 0x0130 0x80 0x32 0x32",
+                "~ access 0x0130 0x80: get required, set optional, inf optional -> get required, set required, inf optional",
+                "~ type 0x0130 0xB0: number(uint8) -> number(uint16)",
+                "~ release 0x0130 0xB0: A..latest -> A..J",
+                "- property 0x0130 0xB1 prop 0xB1",
+                "+ property 0x0130 0xB2 prop 0xB2",
+            ]
+        );
+
+        let json = serde_json::to_string(&diff(&old, &catalogue(""))[0]).unwrap();
+        assert_eq!(
+            json,
+            r#"{"change":"propertyRemoved","class":"0x0130","epc":"0x80","name":"prop 0x80"}"#
+        );
+    }
+}
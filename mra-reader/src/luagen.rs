@@ -0,0 +1,240 @@
+//! Generates a Wireshark Lua dissector for ECHONET Lite.
+
+use crate::catalogue::Catalogue;
+use echonet_lite::mra::{MraClass, TypeDef};
+use std::fmt::Write;
+
+/// Service codes and their names.
+const SERVICE_CODES: &[(u8, &str)] = &[
+    (0x50, "SetI_SNA"),
+    (0x51, "SetC_SNA"),
+    (0x52, "Get_SNA"),
+    (0x53, "INF_SNA"),
+    (0x5E, "SetGet_SNA"),
+    (0x60, "SetI"),
+    (0x61, "SetC"),
+    (0x62, "Get"),
+    (0x63, "INF_REQ"),
+    (0x6E, "SetGet"),
+    (0x71, "Set_Res"),
+    (0x72, "Get_Res"),
+    (0x73, "INF"),
+    (0x74, "INFC"),
+    (0x7A, "INFC_Res"),
+    (0x7E, "SetGet_Res"),
+];
+
+/// Generates a dissector for UDP and TCP port 3610 with class, EPC and state names.
+pub fn dissector(catalogue: &Catalogue) -> String {
+    let mut out = String::new();
+    writeln!(out, "-- ECHONET Lite dissector generated by mra-reader.").unwrap();
+    writeln!(
+        out,
+        "-- Copy this file into the Wireshark plugin directory.\n"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "local echonet = Proto(\"echonetlite\", \"ECHONET Lite\")\n"
+    )
+    .unwrap();
+
+    writeln!(out, "local esv_names = {{").unwrap();
+    for (code, name) in SERVICE_CODES {
+        writeln!(out, "    [0x{code:02X}] = {},", lua_str(name)).unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "local class_names = {{").unwrap();
+    for class in catalogue.classes() {
+        writeln!(
+            out,
+            "    [{}] = {},",
+            class.eoj,
+            lua_str(&class.class_name.en)
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "-- properties of the device object super class").unwrap();
+    write!(out, "local super_properties = ").unwrap();
+    properties(&mut out, &catalogue.super_class, "");
+    writeln!(out, "\n\nlocal properties = {{").unwrap();
+    for class in catalogue.classes() {
+        write!(out, "    [{}] = ", class.eoj).unwrap();
+        properties(&mut out, class, "    ");
+        writeln!(out, ",").unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    out.push_str(DISSECTOR);
+    out
+}
+
+/// Writes a table of `EPC => { name, states }`.
+fn properties(out: &mut String, class: &MraClass, indent: &str) {
+    writeln!(out, "{{").unwrap();
+    for prop in class.latest_properties() {
+        write!(
+            out,
+            "{indent}    [{}] = {{ name = {}",
+            prop.epc,
+            lua_str(&prop.name.en)
+        )
+        .unwrap();
+        if let TypeDef::Inline(def) = &prop.data {
+            if def.size == 1 {
+                write!(out, ", states = {{").unwrap();
+                for state in &def.enumeration {
+                    write!(
+                        out,
+                        " [{}] = {},",
+                        state.edt,
+                        lua_str(&state.descriptions.en)
+                    )
+                    .unwrap();
+                }
+                write!(out, " }}").unwrap();
+            }
+        }
+        writeln!(out, " }},").unwrap();
+    }
+    write!(out, "{indent}}}").unwrap();
+}
+
+/// Quotes `s` as a Lua string literal.
+fn lua_str(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+const DISSECTOR: &str = r#"local fields = echonet.fields
+fields.ehd1 = ProtoField.uint8("echonetlite.ehd1", "EHD1", base.HEX)
+fields.ehd2 = ProtoField.uint8("echonetlite.ehd2", "EHD2", base.HEX)
+fields.tid = ProtoField.uint16("echonetlite.tid", "TID", base.DEC)
+fields.seoj = ProtoField.uint24("echonetlite.seoj", "SEOJ", base.HEX)
+fields.deoj = ProtoField.uint24("echonetlite.deoj", "DEOJ", base.HEX)
+fields.esv = ProtoField.uint8("echonetlite.esv", "ESV", base.HEX, esv_names)
+fields.opc = ProtoField.uint8("echonetlite.opc", "OPC", base.DEC)
+fields.epc = ProtoField.uint8("echonetlite.epc", "EPC", base.HEX)
+fields.pdc = ProtoField.uint8("echonetlite.pdc", "PDC", base.DEC)
+fields.edt = ProtoField.bytes("echonetlite.edt", "EDT")
+
+local function class_name(class)
+    return class_names[class] or "Unknown"
+end
+
+local function property(class, epc)
+    local class_properties = properties[class]
+    if class_properties and class_properties[epc] then
+        return class_properties[epc]
+    end
+    if class >= 0x0EF0 then
+        return nil
+    end
+    return super_properties[epc]
+end
+
+local function add_object(tree, field, eoj)
+    local class = eoj(0, 2):uint()
+    tree:add(field, eoj):append_text(string.format(" (%s, instance %d)", class_name(class), eoj(2, 1):uint()))
+end
+
+-- Dissects OPC and the following properties, returns the next offset.
+local function add_properties(buffer, tree, offset, class)
+    local opc = buffer(offset, 1):uint()
+    tree:add(fields.opc, buffer(offset, 1))
+    offset = offset + 1
+    for _ = 1, opc do
+        if offset + 2 > buffer:len() then
+            break
+        end
+        local epc = buffer(offset, 1):uint()
+        local pdc = buffer(offset + 1, 1):uint()
+        local def = property(class, epc)
+        local name = def and def.name or "Unknown"
+        local subtree = tree:add(echonet, buffer(offset, math.min(2 + pdc, buffer:len() - offset)),
+            string.format("EPC 0x%02X: %s", epc, name))
+        subtree:add(fields.epc, buffer(offset, 1)):append_text(" (" .. name .. ")")
+        subtree:add(fields.pdc, buffer(offset + 1, 1))
+        if pdc > 0 and offset + 2 + pdc <= buffer:len() then
+            local edt = buffer(offset + 2, pdc)
+            local item = subtree:add(fields.edt, edt)
+            if pdc == 1 and def and def.states and def.states[edt:uint()] then
+                item:append_text(" (" .. def.states[edt:uint()] .. ")")
+            end
+        end
+        offset = offset + 2 + pdc
+    end
+    return offset
+end
+
+function echonet.dissector(buffer, pinfo, tree)
+    if buffer:len() < 12 or buffer(0, 1):uint() ~= 0x10 then
+        return 0
+    end
+    pinfo.cols.protocol = "ECHONET Lite"
+
+    local subtree = tree:add(echonet, buffer(), "ECHONET Lite")
+    subtree:add(fields.ehd1, buffer(0, 1))
+    subtree:add(fields.ehd2, buffer(1, 1))
+    subtree:add(fields.tid, buffer(2, 2))
+    -- specified message format only; EHD2 0x82 is followed by an arbitrary message
+    if buffer(1, 1):uint() ~= 0x81 then
+        pinfo.cols.info = "Arbitrary message format"
+        return buffer:len()
+    end
+    add_object(subtree, fields.seoj, buffer(4, 3))
+    add_object(subtree, fields.deoj, buffer(7, 3))
+    subtree:add(fields.esv, buffer(10, 1))
+
+    -- requests and INFC_Res are sent to the device, the others are sent by the device
+    local esv = buffer(10, 1):uint()
+    local device = buffer(4, 2):uint()
+    if (esv >= 0x60 and esv <= 0x6F) or esv == 0x7A then
+        device = buffer(7, 2):uint()
+    end
+
+    local offset = add_properties(buffer, subtree, 11, device)
+    -- SetGet, SetGet_Res and SetGet_SNA have properties to get after the ones to set
+    if (esv == 0x5E or esv == 0x6E or esv == 0x7E) and offset < buffer:len() then
+        offset = add_properties(buffer, subtree, offset, device)
+    end
+
+    pinfo.cols.info = string.format("%s %s", esv_names[esv] or string.format("0x%02X", esv), class_name(device))
+    return offset
+end
+
+DissectorTable.get("udp.port"):add(3610, echonet)
+DissectorTable.get("tcp.port"):add(3610, echonet)
+"#;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn quote_lua_string() {
+        assert_eq!(lua_str(r#"Fault "status""#), r#""Fault \"status\"""#);
+        assert_eq!(lua_str("a\\b"), r#""a\\b""#);
+    }
+
+    #[test]
+    fn check_ehd2_before_properties() {
+        let ehd2 = DISSECTOR.find("buffer(1, 1):uint() ~= 0x81").unwrap();
+        let props = DISSECTOR
+            .find("add_properties(buffer, subtree, 11")
+            .unwrap();
+        assert!(ehd2 < props);
+    }
+}
@@ -1,35 +1,77 @@
+use anyhow::Context;
+use echonet_lite::mra::{self, read_json};
 use std::env;
-use std::fs::File;
-use std::io::prelude::*;
+use std::path::Path;
 
-mod mra;
+mod catalogue;
+mod diff;
+mod luagen;
+mod phfgen;
+mod testgen;
+mod typegen;
 
 fn print_phf(class: &mra::MraClass) {
-    println!(
-        "pub static {}: phf::Map<u8, &'static str> = phf_map! {{",
-        class.short_name
+    print!("{}", phfgen::property_map(&class.short_name, class));
+}
+
+fn print_diff(old: &str, new: &str, json: bool) -> anyhow::Result<()> {
+    let changes = diff::diff(
+        &catalogue::Catalogue::read(Path::new(old))?,
+        &catalogue::Catalogue::read(Path::new(new))?,
     );
-    for prop in &class.properties {
-        println!("    {}u8 => \"{}\",", prop.epc, prop.name.ja);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&changes)?);
+    } else {
+        for change in changes {
+            println!("{change}");
+        }
     }
-    println!("}};");
+    Ok(())
 }
 
-fn main() -> anyhow::Result<()> {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        println!("usage:\nmra-reader MRA_DATA_PATH");
-        anyhow::bail!("invalid command line argument");
-    }
+fn usage() -> anyhow::Result<()> {
+    println!("usage:");
+    println!("mra-reader [phf|types|tests] MRA_DATA_PATH");
+    println!("mra-reader catalogue MRA_DATA_DIR OUTPUT_DIR");
+    println!("mra-reader wireshark MRA_DATA_DIR");
+    println!("mra-reader diff [--json] OLD_MRA_DATA_DIR NEW_MRA_DATA_DIR");
+    anyhow::bail!("invalid command line argument");
+}
 
-    let filename = &args[1];
-    let mut f = File::open(filename)?;
+/// Reads the class and resolves its references with `mraData/definitions/definitions.json`.
+fn read_class(filename: &str) -> anyhow::Result<mra::MraClass> {
+    let path = Path::new(filename);
+    let class: mra::MraClass = read_json(path)?;
 
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)?;
+    let mra_data = path
+        .parent()
+        .and_then(Path::parent)
+        .context("MRA data directory not found")?;
+    let definitions: mra::Definitions =
+        read_json(&mra_data.join("definitions").join("definitions.json"))?;
+    Ok(definitions.resolve_class(class)?)
+}
 
-    let class: mra::MraClass = serde_json::from_str(&contents)?;
-    print_phf(&class);
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    match args.len() {
+        2 => print_phf(&read_class(&args[1])?),
+        3 if args[1] == "phf" => print_phf(&read_class(&args[2])?),
+        4 if args[1] == "catalogue" => {
+            catalogue::Catalogue::read(Path::new(&args[2]))?.write(Path::new(&args[3]))?
+        }
+        3 if args[1] == "wireshark" => {
+            print!(
+                "{}",
+                luagen::dissector(&catalogue::Catalogue::read(Path::new(&args[2]))?)
+            )
+        }
+        4 if args[1] == "diff" => print_diff(&args[2], &args[3], false)?,
+        5 if args[1] == "diff" && args[2] == "--json" => print_diff(&args[3], &args[4], true)?,
+        3 if args[1] == "types" => print!("{}", typegen::generate(&read_class(&args[2])?)),
+        3 if args[1] == "tests" => print!("{}", testgen::generate(&read_class(&args[2])?)),
+        _ => return usage(),
+    }
 
     Ok(())
 }
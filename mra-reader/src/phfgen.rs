@@ -0,0 +1,79 @@
+//! Generates `phf::Map<u8, PropertyDef>` property maps used in echonet-lite crate.
+
+use echonet_lite::mra::{self, TypeDef};
+use std::fmt::Write;
+
+/// Generates a property map named `name` for the properties of `class`.
+pub fn property_map(name: &str, class: &mra::MraClass) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "pub static {name}: phf::Map<u8, PropertyDef> = phf_map! {{"
+    )
+    .unwrap();
+    for prop in class.latest_properties() {
+        write!(
+            out,
+            "    {}u8 => PropertyDef::new({:?}, {:?})",
+            prop.epc, prop.name.ja, prop.name.en
+        )
+        .unwrap();
+        write!(out, ".access({})", access_rule(&prop.access_rule)).unwrap();
+        if !prop.valid_release.is_all() {
+            write!(out, ".releases({})", release_range(&prop.valid_release)).unwrap();
+        }
+        match &prop.data {
+            TypeDef::Inline(def) if def.size == 1 => {
+                writeln!(out, ".states(&[").unwrap();
+                for state in &def.enumeration {
+                    writeln!(
+                        out,
+                        "        ({}, LocalizedName::new({:?}, {:?})),",
+                        state.edt, state.descriptions.ja, state.descriptions.en
+                    )
+                    .unwrap();
+                }
+                writeln!(out, "    ]),").unwrap();
+            }
+            TypeDef::Number(def) => {
+                let exponent = def.multiple.map_or(0, |m| m.log10().round() as i8);
+                let unit = def.unit.as_deref().unwrap_or_default();
+                let kind = if def.signed() { "signed" } else { "unsigned" };
+                writeln!(
+                    out,
+                    ".number(NumberDef::{kind}({}, {exponent}, {unit:?})),",
+                    def.size()
+                )
+                .unwrap();
+            }
+            _ => writeln!(out, ",").unwrap(),
+        }
+    }
+    writeln!(out, "}};").unwrap();
+    out
+}
+
+/// Generates the `ReleaseRange` expression of `release`.
+pub fn release_range(release: &mra::ValidRelease) -> String {
+    if release.to == "latest" {
+        format!("ReleaseRange::since(b'{}')", release.from)
+    } else {
+        format!("ReleaseRange::new(b'{}', b'{}')", release.from, release.to)
+    }
+}
+
+/// Generates the `AccessRule` expression of `rule`.
+pub fn access_rule(rule: &mra::AccessRule) -> String {
+    let access = |access: &str| match access {
+        "notApplicable" => "Access::NotApplicable",
+        "optional" => "Access::Optional",
+        // including conditionally required ones
+        _ => "Access::Required",
+    };
+    format!(
+        "AccessRule::new({}, {}, {})",
+        access(&rule.get),
+        access(&rule.set),
+        access(&rule.inf)
+    )
+}
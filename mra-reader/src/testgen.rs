@@ -0,0 +1,341 @@
+//! Generates round-trip test vectors from the enumerations and ranges of the MRA.
+//!
+//! The tests check the typed EDT decoders of [`typegen`](crate::typegen) and the `Display`
+//! of the values decoded with the property map of [`phfgen`](crate::phfgen).
+
+use crate::phfgen::property_map;
+use crate::typegen::{self, byte_list, fixed_size, snake, snake_case, type_name, variant_names};
+use echonet_lite::mra::{self, TypeDef};
+use std::fmt::Write;
+
+/// Property map of the generated file, used for `Display` checks.
+const MAP: &str = "PROPERTIES";
+
+/// Generates a self-contained file with the property map, the typed EDTs and tests for `class`.
+pub fn generate(class: &mra::MraClass) -> String {
+    let mut out = String::new();
+    writeln!(out, "use echonet_lite::object::*;").unwrap();
+    writeln!(out, "use phf::phf_map;\n").unwrap();
+    writeln!(out, "{}", property_map(MAP, class)).unwrap();
+    writeln!(out, "{}", typegen::generate(class)).unwrap();
+
+    let mut tests = String::new();
+    for prop in class.latest_properties() {
+        let name = type_name(&prop.short_name);
+        let epc = u8::from_str_radix(prop.epc.trim_start_matches("0x"), 16).unwrap_or_default();
+        writeln!(tests, "\n    /// {} ({})", prop.name.ja, prop.name.en).unwrap();
+        writeln!(tests, "    #[test]").unwrap();
+        writeln!(
+            tests,
+            "    fn epc_{epc:02x}_{}() {{",
+            snake_case(&prop.short_name)
+        )
+        .unwrap();
+        for line in vectors(&name, epc, &prop.data) {
+            writeln!(tests, "        {line}").unwrap();
+        }
+        writeln!(tests, "    }}").unwrap();
+    }
+
+    writeln!(out, "#[cfg(test)]").unwrap();
+    writeln!(out, "mod test {{").unwrap();
+    writeln!(out, "    use super::{}::*;", snake(&class.short_name)).unwrap();
+    writeln!(out, "    use super::*;").unwrap();
+    if tests.contains("display(") {
+        writeln!(
+            out,
+            "\n    /// Decodes `edt` with the property map and displays it in English."
+        )
+        .unwrap();
+        writeln!(out, "    fn display(epc: u8, edt: &[u8]) -> String {{").unwrap();
+        writeln!(
+            out,
+            "        let value = {MAP}.get(&epc).unwrap().decode(edt).unwrap();"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        Localized::new(&value, Locale::En).to_string()"
+        )
+        .unwrap();
+        writeln!(out, "    }}").unwrap();
+    }
+    out.push_str(&tests);
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+/// Assertions for valid and invalid EDTs of type `name`.
+fn vectors(name: &str, epc: u8, def: &TypeDef) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut valid = |edt: &str, value: &str, display: Option<String>| {
+        lines.push(format!(
+            "assert_eq!({name}::decode(&[{edt}]), Some({value}));"
+        ));
+        lines.push(format!("assert_eq!({value}.encode(), [{edt}]);"));
+        if let Some(display) = display {
+            lines.push(format!(
+                "assert_eq!(display(0x{epc:02X}, &[{edt}]), {display:?});"
+            ));
+        }
+    };
+
+    match def {
+        TypeDef::Inline(def) => {
+            let variants = variant_names(def.enumeration.iter().map(|v| (&v.name, &v.edt)));
+            for (variant, state) in variants.iter().zip(&def.enumeration) {
+                // only 1 byte states have names in the property map
+                let display = (def.size == 1).then(|| state.descriptions.en.clone());
+                valid(
+                    &byte_list(&state.edt, def.size),
+                    &format!("{name}::{variant}"),
+                    display,
+                );
+            }
+        }
+        TypeDef::Numeric(def) => {
+            for value in &def.enumerations {
+                valid(
+                    &byte_list(&value.edt, def.size),
+                    &format!("{name}({:?})", value.value),
+                    None,
+                );
+            }
+        }
+        TypeDef::Number(def) => {
+            for value in number_values(def) {
+                valid(
+                    &number_bytes(value, def.size()),
+                    &format!("{name}({value})"),
+                    number_display(def, value),
+                );
+            }
+            for (code, check) in [
+                (def.overflow(), "is_overflow"),
+                (def.underflow(), "is_underflow"),
+            ] {
+                if let Some(code) = code {
+                    let edt = number_bytes(code, def.size());
+                    lines.push(format!(
+                        "assert!({name}::decode(&[{edt}]).unwrap().{check}());"
+                    ));
+                }
+            }
+        }
+        TypeDef::Level(def) => {
+            let base = parse_hex(&def.base) as u8;
+            for level in [1, def.maximum].into_iter().filter(|level| *level > 0) {
+                let edt = base.wrapping_add(level - 1);
+                valid(&format!("0x{edt:02X}"), &format!("{name}({level})"), None);
+            }
+        }
+        _ => {
+            if let Some(edt) = sample(def) {
+                let edt = bytes(&edt);
+                lines.push(format!(
+                    "assert_eq!({name}::decode(&[{edt}]).map(|value| value.encode()), Some(vec![{edt}]));"
+                ));
+            }
+        }
+    }
+
+    for edt in invalid(def) {
+        lines.push(format!(
+            "assert_eq!({name}::decode(&[{}]), None);",
+            bytes(&edt)
+        ));
+    }
+    lines
+}
+
+/// EDTs the decoder of `def` must reject.
+fn invalid(def: &TypeDef) -> Vec<Vec<u8>> {
+    let mut edts = Vec::new();
+    if let Some(size) = fixed_size(def) {
+        edts.push(vec![0; size + 1]);
+        if size > 0 {
+            edts.push(vec![0; size - 1]);
+        }
+    }
+
+    match def {
+        TypeDef::Inline(def) if def.size == 1 => {
+            let states: Vec<_> = def.enumeration.iter().map(|s| parse_hex(&s.edt)).collect();
+            if let Some(unused) = (0..=0xFF).find(|edt| !states.contains(edt)) {
+                edts.push(vec![unused as u8]);
+            }
+        }
+        TypeDef::Number(def) => {
+            let (min, max) = number_range(def);
+            let codes = [def.overflow(), def.underflow()];
+            let outside = [
+                def.minimum.map(|m| m - 1).filter(|v| *v >= min),
+                def.maximum.map(|m| m + 1).filter(|v| *v <= max),
+            ];
+            for value in outside.into_iter().flatten() {
+                if !codes.contains(&Some(value)) {
+                    edts.push(to_bytes(value, def.size()));
+                }
+            }
+        }
+        TypeDef::Level(def) => {
+            let base = parse_hex(&def.base);
+            if base > 0 {
+                edts.push(vec![base as u8 - 1]);
+            }
+            if base + (def.maximum as u64) <= 0xFF {
+                edts.push(vec![(base + def.maximum as u64) as u8]);
+            }
+        }
+        TypeDef::Raw(def) if def.min_size != def.max_size => {
+            if def.min_size > 0 {
+                edts.push(vec![0; def.min_size - 1]);
+            }
+            if def.max_size < 0xFF {
+                edts.push(vec![0; def.max_size + 1]);
+            }
+        }
+        // items without a fixed size are kept as raw bytes
+        TypeDef::Array(def) if def.item_size > 0 => {
+            if def.item_size > 1 {
+                edts.push(vec![0; def.item_size + 1]);
+            }
+            if (def.max_items + 1) * def.item_size <= 0xFF {
+                edts.push(vec![0; (def.max_items + 1) * def.item_size]);
+            }
+        }
+        _ => {}
+    }
+    edts
+}
+
+/// A valid EDT of `def`, if one can be made from the definition.
+fn sample(def: &TypeDef) -> Option<Vec<u8>> {
+    match def {
+        TypeDef::Inline(def) => {
+            let state = def.enumeration.first()?;
+            Some(to_bytes(parse_hex(&state.edt) as i64, def.size))
+        }
+        TypeDef::Numeric(def) => {
+            let value = def.enumerations.first()?;
+            Some(to_bytes(parse_hex(&value.edt) as i64, def.size))
+        }
+        TypeDef::Number(def) => Some(to_bytes(*number_values(def).first()?, def.size())),
+        TypeDef::Level(def) => Some(vec![parse_hex(&def.base) as u8]),
+        TypeDef::BitMap(def) => Some(vec![0; def.size]),
+        TypeDef::Raw(def) => Some(vec![0; def.min_size]),
+        TypeDef::DateTime(def) => Some(vec![0; def.size]),
+        TypeDef::Object(def) => {
+            let elements: Option<Vec<_>> =
+                def.properties.iter().map(|e| sample(&e.element)).collect();
+            Some(elements?.concat())
+        }
+        TypeDef::OneOf(def) => def.one_of.iter().find_map(sample),
+        TypeDef::Array(def) if def.max_items > 0 => {
+            sample(&def.items).filter(|item| item.len() == def.item_size)
+        }
+        TypeDef::Array(_) => Some(Vec::new()),
+        TypeDef::Ref(_) => None,
+    }
+}
+
+/// Minimum and maximum of the number, or 0 if it has no range.
+fn number_values(def: &mra::DefNumber) -> Vec<i64> {
+    let (min, max) = number_range(def);
+    let in_range = |v: &i64| (min..=max).contains(v);
+    let values: Vec<_> = [def.minimum, def.maximum]
+        .into_iter()
+        .flatten()
+        .filter(in_range)
+        .collect();
+    match values.as_slice() {
+        [] => vec![0],
+        [min, max] if min == max => vec![*min],
+        _ => values,
+    }
+}
+
+/// Range of the integer type of the number.
+fn number_range(def: &mra::DefNumber) -> (i64, i64) {
+    let bits = def.size() as u32 * 8;
+    if def.signed() {
+        (-(1i64 << (bits - 1)), (1i64 << (bits - 1)) - 1)
+    } else {
+        (0, (1i64 << bits) - 1)
+    }
+}
+
+/// Expected display of the number, if the property map can decode it exactly.
+fn number_display(def: &mra::DefNumber, value: i64) -> Option<String> {
+    let multiple = def.multiple.unwrap_or(1.0);
+    let exponent = multiple.log10().round() as i32;
+    // the property map only has powers of ten
+    if (10f64.powi(exponent) - multiple).abs() > f64::EPSILON * multiple {
+        return None;
+    }
+    let mut display = if exponent >= 0 {
+        (value * 10i64.pow(exponent as u32)).to_string()
+    } else {
+        let digits = exponent.unsigned_abs() as usize;
+        format!("{:.digits$}", value as f64 * multiple)
+    };
+    if let Some(unit) = def.unit.as_deref().filter(|unit| !unit.is_empty()) {
+        write!(display, " {unit}").unwrap();
+    }
+    Some(display)
+}
+
+fn number_bytes(value: i64, size: usize) -> String {
+    bytes(&to_bytes(value, size))
+}
+
+/// Big endian bytes of `value` in two's complement.
+fn to_bytes(value: i64, size: usize) -> Vec<u8> {
+    (value as u64).to_be_bytes()[8 - size.min(8)..].to_vec()
+}
+
+fn bytes(edt: &[u8]) -> String {
+    edt.iter()
+        .map(|b| format!("0x{b:02X}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn parse_hex(hex: &str) -> u64 {
+    u64::from_str_radix(hex.trim_start_matches("0x"), 16).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn number(format: &str, minimum: Option<i64>, maximum: Option<i64>) -> mra::DefNumber {
+        mra::DefNumber {
+            t: "number".to_string(),
+            format: format.to_string(),
+            minimum,
+            maximum,
+            unit: Some("Celsius".to_string()),
+            multiple: Some(0.1),
+            coefficient: Vec::new(),
+            overflow_code: true,
+            underflow_code: false,
+        }
+    }
+
+    #[test]
+    fn number_vectors() {
+        let def = number("int16", Some(-100), Some(500));
+        assert_eq!(number_values(&def), [-100, 500]);
+        assert_eq!(number_display(&def, -100).unwrap(), "-10.0 Celsius");
+        assert_eq!(
+            invalid(&TypeDef::Number(def.clone())),
+            [vec![0; 3], vec![0; 1], vec![0xFF, 0x9B], vec![0x01, 0xF5]]
+        );
+
+        // the maximum of uint8 is the overflow code
+        let def = number("uint8", Some(0), Some(0xFF));
+        assert_eq!(to_bytes(def.overflow().unwrap(), 1), [0xFF]);
+        assert_eq!(invalid(&TypeDef::Number(def)), [vec![0; 2], vec![]]);
+    }
+}
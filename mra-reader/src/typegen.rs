@@ -0,0 +1,769 @@
+//! Generates typed Rust EDT definitions from MRA `TypeDef`s.
+//!
+//! Every generated type has `decode(&[u8]) -> Option<Self>` and `encode(&self) -> Vec<u8>`.
+
+use echonet_lite::mra::{self, TypeDef};
+use std::fmt::Write;
+
+/// Generates a module containing EDT types for all the properties of `class`.
+pub fn generate(class: &mra::MraClass) -> String {
+    let mut gen = TypeGen::default();
+    for prop in class.latest_properties() {
+        let name = type_name(&prop.short_name);
+        let doc = format!("{} ({})", prop.name.ja, prop.name.en);
+        gen.gen_type(&name, &doc, &prop.data);
+        writeln!(
+            gen.out,
+            "impl {name} {{\n    pub const EPC: u8 = {};\n}}\n",
+            prop.epc
+        )
+        .unwrap();
+    }
+
+    let mut out = String::new();
+    writeln!(out, "/// {} ({})", class.class_name.ja, class.class_name.en).unwrap();
+    writeln!(out, "pub mod {} {{", snake(&class.short_name)).unwrap();
+    for line in gen.out.lines() {
+        if line.is_empty() {
+            out.push('\n');
+        } else {
+            writeln!(out, "    {line}").unwrap();
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+#[derive(Default)]
+struct TypeGen {
+    out: String,
+}
+
+impl TypeGen {
+    /// Emits the definition of type `name` and its dependencies.
+    fn gen_type(&mut self, name: &str, doc: &str, def: &TypeDef) {
+        match def {
+            TypeDef::Inline(def) => self.gen_state(name, doc, def),
+            TypeDef::Numeric(def) => self.gen_numeric(name, doc, def),
+            TypeDef::BitMap(def) => self.gen_bitmap(name, doc, def),
+            TypeDef::Array(def) => self.gen_array(name, doc, def),
+            TypeDef::Object(def) => self.gen_object(name, doc, def),
+            TypeDef::OneOf(def) => self.gen_one_of(name, doc, def),
+            TypeDef::Number(def) => self.gen_number(name, doc, def),
+            TypeDef::Level(def) => self.gen_level(name, doc, def),
+            TypeDef::Raw(def) => self.gen_raw(name, doc, def.min_size, def.max_size),
+            TypeDef::DateTime(def) => self.gen_raw(name, doc, def.size, def.size),
+            // Unresolved references are kept as raw bytes.
+            TypeDef::Ref(_) => self.gen_raw(name, doc, 0, usize::MAX),
+        }
+    }
+
+    fn gen_raw(&mut self, name: &str, doc: &str, min_size: usize, max_size: usize) {
+        let out = &mut self.out;
+        writeln!(out, "/// {doc}").unwrap();
+        writeln!(out, "#[derive(Debug, Clone, PartialEq, Eq)]").unwrap();
+        writeln!(out, "pub struct {name}(pub Vec<u8>);\n").unwrap();
+        writeln!(out, "impl {name} {{").unwrap();
+        writeln!(out, "    pub fn decode(edt: &[u8]) -> Option<Self> {{").unwrap();
+        let mut conditions = Vec::new();
+        if min_size > 0 {
+            conditions.push(format!("edt.len() < {min_size}"));
+        }
+        if max_size < usize::MAX {
+            conditions.push(format!("edt.len() > {max_size}"));
+        }
+        if !conditions.is_empty() {
+            writeln!(out, "        if {} {{", conditions.join(" || ")).unwrap();
+            writeln!(out, "            return None;").unwrap();
+            writeln!(out, "        }}").unwrap();
+        }
+        writeln!(out, "        Some(Self(edt.to_vec()))").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn encode(&self) -> Vec<u8> {{").unwrap();
+        writeln!(out, "        self.0.clone()").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn gen_number(&mut self, name: &str, doc: &str, def: &mra::DefNumber) {
+        let ty = number_type(def);
+        let size = def.size();
+        let out = &mut self.out;
+        writeln!(out, "/// {doc}").unwrap();
+        writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+        writeln!(out, "pub struct {name}(pub {ty});\n").unwrap();
+        writeln!(out, "impl {name} {{").unwrap();
+        if let Some(unit) = &def.unit {
+            writeln!(out, "    pub const UNIT: &'static str = {unit:?};").unwrap();
+        }
+        let multiple = def.multiple.unwrap_or(1.0);
+        writeln!(out, "    pub const MULTIPLE: f64 = {multiple:?};").unwrap();
+        let coefficient: Vec<_> = def
+            .coefficient
+            .iter()
+            .map(|epc| byte_list(epc, 1))
+            .collect();
+        writeln!(
+            out,
+            "    /// EPCs of the properties to multiply the value with.\n    pub const COEFFICIENT: &'static [u8] = &[{}];",
+            coefficient.join(", ")
+        )
+        .unwrap();
+        let code = |code: Option<i64>| match code {
+            Some(code) => format!("Some({code}{ty})"),
+            None => "None".to_string(),
+        };
+        writeln!(
+            out,
+            "    pub const OVERFLOW: Option<{ty}> = {};",
+            code(def.overflow())
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    pub const UNDERFLOW: Option<{ty}> = {};\n",
+            code(def.underflow())
+        )
+        .unwrap();
+        writeln!(out, "    /// Value scaled by `MULTIPLE`.").unwrap();
+        writeln!(out, "    pub fn value(&self) -> f64 {{").unwrap();
+        writeln!(out, "        self.0 as f64 * Self::MULTIPLE").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn is_overflow(&self) -> bool {{").unwrap();
+        writeln!(out, "        Self::OVERFLOW == Some(self.0)").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn is_underflow(&self) -> bool {{").unwrap();
+        writeln!(out, "        Self::UNDERFLOW == Some(self.0)").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn decode(edt: &[u8]) -> Option<Self> {{").unwrap();
+        writeln!(
+            out,
+            "        let value = {ty}::from_be_bytes(<[u8; {size}]>::try_from(edt).ok()?);"
+        )
+        .unwrap();
+        writeln!(out, "        let value = Self(value);").unwrap();
+        let mut range = Vec::new();
+        // Unsigned values are never below 0.
+        if let Some(minimum) = def.minimum.filter(|m| def.signed() || *m > 0) {
+            range.push(format!("value.0 >= {minimum}"));
+        }
+        if let Some(maximum) = def.maximum {
+            range.push(format!("value.0 <= {maximum}"));
+        }
+        if range.is_empty() {
+            writeln!(out, "        Some(value)").unwrap();
+        } else {
+            writeln!(
+                out,
+                "        let valid = ({}) || value.is_overflow() || value.is_underflow();",
+                range.join(" && ")
+            )
+            .unwrap();
+            writeln!(out, "        valid.then_some(value)").unwrap();
+        }
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn encode(&self) -> Vec<u8> {{").unwrap();
+        writeln!(out, "        self.0.to_be_bytes().to_vec()").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn gen_level(&mut self, name: &str, doc: &str, def: &mra::DefLevel) {
+        let base = byte_list(&def.base, 1);
+        let maximum = def.maximum;
+        let out = &mut self.out;
+        writeln!(out, "/// {doc}").unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(out, "/// Levels are numbered from 1 to {maximum}.").unwrap();
+        writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+        writeln!(out, "pub struct {name}(pub u8);\n").unwrap();
+        writeln!(out, "impl {name} {{").unwrap();
+        writeln!(out, "    pub fn decode(edt: &[u8]) -> Option<Self> {{").unwrap();
+        writeln!(out, "        match edt {{").unwrap();
+        writeln!(
+            out,
+            "            [edt] if (0..{maximum}).contains(&edt.wrapping_sub({base})) => {{"
+        )
+        .unwrap();
+        writeln!(out, "                Some(Self(edt - {base} + 1))").unwrap();
+        writeln!(out, "            }}").unwrap();
+        writeln!(out, "            _ => None,").unwrap();
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn encode(&self) -> Vec<u8> {{").unwrap();
+        writeln!(out, "        vec![self.0 + {base} - 1]").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn gen_state(&mut self, name: &str, doc: &str, def: &mra::DefInline) {
+        let variants = variant_names(def.enumeration.iter().map(|v| (&v.name, &v.edt)));
+        let out = &mut self.out;
+        writeln!(out, "/// {doc}").unwrap();
+        writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+        writeln!(out, "pub enum {name} {{").unwrap();
+        for (variant, state) in variants.iter().zip(&def.enumeration) {
+            let desc = &state.descriptions;
+            writeln!(out, "    /// {} ({})", desc.ja, desc.en).unwrap();
+            writeln!(out, "    {variant},").unwrap();
+        }
+        writeln!(out, "}}\n").unwrap();
+        writeln!(out, "impl {name} {{").unwrap();
+        writeln!(out, "    pub fn decode(edt: &[u8]) -> Option<Self> {{").unwrap();
+        writeln!(out, "        match edt {{").unwrap();
+        for (variant, state) in variants.iter().zip(&def.enumeration) {
+            let bytes = byte_list(&state.edt, def.size);
+            writeln!(out, "            [{bytes}] => Some(Self::{variant}),").unwrap();
+        }
+        writeln!(out, "            _ => None,").unwrap();
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn encode(&self) -> Vec<u8> {{").unwrap();
+        writeln!(out, "        match self {{").unwrap();
+        for (variant, state) in variants.iter().zip(&def.enumeration) {
+            let bytes = byte_list(&state.edt, def.size);
+            writeln!(out, "            Self::{variant} => vec![{bytes}],").unwrap();
+        }
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn gen_numeric(&mut self, name: &str, doc: &str, def: &mra::DefNumeric) {
+        let out = &mut self.out;
+        writeln!(out, "/// {doc}").unwrap();
+        writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq)]").unwrap();
+        writeln!(out, "pub struct {name}(pub f64);\n").unwrap();
+        writeln!(out, "impl {name} {{").unwrap();
+        writeln!(out, "    const TABLE: &'static [(&'static [u8], f64)] = &[").unwrap();
+        for value in &def.enumerations {
+            let bytes = byte_list(&value.edt, def.size);
+            writeln!(out, "        (&[{bytes}], {:?}),", value.value).unwrap();
+        }
+        writeln!(out, "    ];\n").unwrap();
+        writeln!(out, "    pub fn decode(edt: &[u8]) -> Option<Self> {{").unwrap();
+        writeln!(out, "        Self::TABLE").unwrap();
+        writeln!(out, "            .iter()").unwrap();
+        writeln!(out, "            .find(|(bytes, _)| *bytes == edt)").unwrap();
+        writeln!(out, "            .map(|(_, value)| Self(*value))").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn encode(&self) -> Vec<u8> {{").unwrap();
+        writeln!(out, "        Self::TABLE").unwrap();
+        writeln!(out, "            .iter()").unwrap();
+        writeln!(out, "            .find(|(_, value)| *value == self.0)").unwrap();
+        writeln!(out, "            .map(|(bytes, _)| bytes.to_vec())").unwrap();
+        writeln!(out, "            .unwrap_or_default()").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn gen_bitmap(&mut self, name: &str, doc: &str, def: &mra::DefBitMapType) {
+        let size = def.size;
+        let out = &mut self.out;
+        writeln!(out, "/// {doc}").unwrap();
+        writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]").unwrap();
+        writeln!(out, "pub struct {name}(pub [u8; {size}]);\n").unwrap();
+        writeln!(out, "impl {name} {{").unwrap();
+        for bitmap in &def.bitmaps {
+            let field = snake(&bitmap.name);
+            let setter = format!("set_{}", field.trim_start_matches("r#"));
+            let index = bitmap.position.index;
+            let mask = parse_bitmask(&bitmap.position.bitmask);
+            let shift = mask.trailing_zeros();
+            let desc = &bitmap.descriptions;
+            writeln!(out, "    /// {} ({})", desc.ja, desc.en).unwrap();
+            if mask.count_ones() == 1 {
+                writeln!(out, "    pub fn {field}(&self) -> bool {{").unwrap();
+                writeln!(out, "        self.0[{index}] & 0x{mask:02X} != 0").unwrap();
+                writeln!(out, "    }}\n").unwrap();
+                writeln!(out, "    pub fn {setter}(&mut self, value: bool) {{").unwrap();
+                writeln!(out, "        if value {{").unwrap();
+                writeln!(out, "            self.0[{index}] |= 0x{mask:02X};").unwrap();
+                writeln!(out, "        }} else {{").unwrap();
+                writeln!(out, "            self.0[{index}] &= !0x{mask:02X};").unwrap();
+                writeln!(out, "        }}").unwrap();
+                writeln!(out, "    }}\n").unwrap();
+            } else {
+                writeln!(out, "    pub fn {field}(&self) -> u8 {{").unwrap();
+                writeln!(out, "        (self.0[{index}] & 0x{mask:02X}) >> {shift}").unwrap();
+                writeln!(out, "    }}\n").unwrap();
+                writeln!(out, "    pub fn {setter}(&mut self, value: u8) {{").unwrap();
+                writeln!(
+                    out,
+                    "        self.0[{index}] = (self.0[{index}] & !0x{mask:02X}) | ((value << {shift}) & 0x{mask:02X});"
+                )
+                .unwrap();
+                writeln!(out, "    }}\n").unwrap();
+            }
+        }
+        writeln!(out, "    pub fn decode(edt: &[u8]) -> Option<Self> {{").unwrap();
+        writeln!(out, "        edt.try_into().ok().map(Self)").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn encode(&self) -> Vec<u8> {{").unwrap();
+        writeln!(out, "        self.0.to_vec()").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn gen_array(&mut self, name: &str, doc: &str, def: &mra::DefArray) {
+        // items without a fixed size cannot be split
+        if def.item_size == 0 {
+            return self.gen_raw(name, doc, 0, usize::MAX);
+        }
+        let item = format!("{name}Item");
+        self.gen_type(&item, &format!("An item of {name}"), &def.items);
+        let out = &mut self.out;
+        writeln!(out, "/// {doc}").unwrap();
+        writeln!(out, "#[derive(Debug, Clone, PartialEq)]").unwrap();
+        writeln!(out, "pub struct {name}(pub Vec<{item}>);\n").unwrap();
+        writeln!(out, "impl {name} {{").unwrap();
+        writeln!(out, "    pub fn decode(edt: &[u8]) -> Option<Self> {{").unwrap();
+        writeln!(
+            out,
+            "        if edt.len() % {} != 0 || edt.len() / {} > {} {{",
+            def.item_size, def.item_size, def.max_items
+        )
+        .unwrap();
+        writeln!(out, "            return None;").unwrap();
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "        edt.chunks({})", def.item_size).unwrap();
+        writeln!(out, "            .map({item}::decode)").unwrap();
+        writeln!(out, "            .collect::<Option<Vec<_>>>()").unwrap();
+        writeln!(out, "            .map(Self)").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn encode(&self) -> Vec<u8> {{").unwrap();
+        writeln!(
+            out,
+            "        self.0.iter().flat_map({item}::encode).collect()"
+        )
+        .unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn gen_object(&mut self, name: &str, doc: &str, def: &mra::DefObject) {
+        let last = def.properties.len().saturating_sub(1);
+        let sizes: Vec<_> = def
+            .properties
+            .iter()
+            .map(|e| fixed_size(&e.element))
+            .collect();
+        if sizes[..last].iter().any(Option::is_none) {
+            // Elements can not be split without their sizes.
+            return self.gen_raw(name, doc, 0, usize::MAX);
+        }
+
+        let fields: Vec<_> = def
+            .properties
+            .iter()
+            .map(|element| {
+                let ty = format!("{name}{}", pascal(&element.short_name));
+                let doc = format!("{} ({})", element.name.ja, element.name.en);
+                self.gen_type(&ty, &doc, &element.element);
+                (snake(&element.short_name), ty, doc)
+            })
+            .collect();
+
+        let out = &mut self.out;
+        writeln!(out, "/// {doc}").unwrap();
+        writeln!(out, "#[derive(Debug, Clone, PartialEq)]").unwrap();
+        writeln!(out, "pub struct {name} {{").unwrap();
+        for (field, ty, doc) in &fields {
+            writeln!(out, "    /// {doc}").unwrap();
+            writeln!(out, "    pub {field}: {ty},").unwrap();
+        }
+        writeln!(out, "}}\n").unwrap();
+        writeln!(out, "impl {name} {{").unwrap();
+        writeln!(out, "    pub fn decode(edt: &[u8]) -> Option<Self> {{").unwrap();
+        writeln!(out, "        let rest = edt;").unwrap();
+        for ((field, ty, _), size) in fields.iter().zip(&sizes) {
+            match size {
+                Some(size) => {
+                    writeln!(
+                        out,
+                        "        let {field} = {ty}::decode(rest.get(..{size})?)?;"
+                    )
+                    .unwrap();
+                    writeln!(out, "        let rest = &rest[{size}..];").unwrap();
+                }
+                None => {
+                    writeln!(out, "        let {field} = {ty}::decode(rest)?;").unwrap();
+                    writeln!(out, "        let rest: &[u8] = &[];").unwrap();
+                }
+            }
+        }
+        writeln!(out, "        if !rest.is_empty() {{").unwrap();
+        writeln!(out, "            return None;").unwrap();
+        writeln!(out, "        }}").unwrap();
+        let field_list: Vec<_> = fields.iter().map(|(field, _, _)| field.as_str()).collect();
+        writeln!(out, "        Some(Self {{ {} }})", field_list.join(", ")).unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn encode(&self) -> Vec<u8> {{").unwrap();
+        writeln!(out, "        let mut edt = Vec::new();").unwrap();
+        for (field, _, _) in &fields {
+            writeln!(out, "        edt.extend(self.{field}.encode());").unwrap();
+        }
+        writeln!(out, "        edt").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn gen_one_of(&mut self, name: &str, doc: &str, def: &mra::DefOneOf) {
+        // Enumerations are tried first, since other types accept most of the EDTs.
+        let mut alternatives: Vec<_> = def.one_of.iter().enumerate().collect();
+        alternatives.sort_by_key(|(_, def)| !is_enumeration(def));
+
+        let kinds: Vec<_> = def
+            .one_of
+            .iter()
+            .map(|def| match def {
+                TypeDef::Inline(_) => Some("State"),
+                TypeDef::Numeric(_) => Some("Numeric"),
+                TypeDef::Number(_) => Some("Number"),
+                TypeDef::Level(_) => Some("Level"),
+                _ => None,
+            })
+            .collect();
+        let variants: Vec<_> = def
+            .one_of
+            .iter()
+            .zip(&kinds)
+            .enumerate()
+            .map(|(i, (def, kind))| {
+                // alternatives of the same kind are told apart by their indices
+                let variant = match kind {
+                    Some(kind) if kinds.iter().filter(|k| *k == &Some(*kind)).count() > 1 => {
+                        format!("{kind}{i}")
+                    }
+                    Some(kind) => kind.to_string(),
+                    None => format!("Alt{i}"),
+                };
+                let ty = format!("{name}{variant}");
+                self.gen_type(&ty, &format!("{variant} of {name}"), def);
+                (variant, ty)
+            })
+            .collect();
+
+        let out = &mut self.out;
+        writeln!(out, "/// {doc}").unwrap();
+        writeln!(out, "#[derive(Debug, Clone, PartialEq)]").unwrap();
+        writeln!(out, "pub enum {name} {{").unwrap();
+        for (variant, ty) in &variants {
+            writeln!(out, "    {variant}({ty}),").unwrap();
+        }
+        writeln!(out, "}}\n").unwrap();
+        writeln!(out, "impl {name} {{").unwrap();
+        writeln!(out, "    pub fn decode(edt: &[u8]) -> Option<Self> {{").unwrap();
+        for (i, _) in &alternatives {
+            let (variant, ty) = &variants[*i];
+            writeln!(out, "        if let Some(value) = {ty}::decode(edt) {{").unwrap();
+            writeln!(out, "            return Some(Self::{variant}(value));").unwrap();
+            writeln!(out, "        }}").unwrap();
+        }
+        writeln!(out, "        None").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(out, "    pub fn encode(&self) -> Vec<u8> {{").unwrap();
+        writeln!(out, "        match self {{").unwrap();
+        for (variant, _) in &variants {
+            writeln!(out, "            Self::{variant}(value) => value.encode(),").unwrap();
+        }
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+}
+
+/// Rust integer type for a number `format`.
+pub(crate) fn number_type(def: &mra::DefNumber) -> String {
+    let bits = def.size() * 8;
+    if def.signed() {
+        format!("i{bits}")
+    } else {
+        format!("u{bits}")
+    }
+}
+
+fn is_enumeration(def: &TypeDef) -> bool {
+    matches!(def, TypeDef::Inline(_) | TypeDef::Numeric(_))
+}
+
+/// Returns the EDT size of `def` if it is fixed.
+pub(crate) fn fixed_size(def: &TypeDef) -> Option<usize> {
+    match def {
+        TypeDef::Inline(def) => Some(def.size),
+        TypeDef::Numeric(def) => Some(def.size),
+        TypeDef::BitMap(def) => Some(def.size),
+        TypeDef::Object(def) => def.properties.iter().map(|e| fixed_size(&e.element)).sum(),
+        TypeDef::OneOf(def) => {
+            let sizes: Option<Vec<_>> = def.one_of.iter().map(fixed_size).collect();
+            let sizes = sizes?;
+            let first = *sizes.first()?;
+            sizes.iter().all(|size| *size == first).then_some(first)
+        }
+        TypeDef::Number(def) => Some(def.size()),
+        TypeDef::Level(_) => Some(1),
+        TypeDef::Raw(def) => (def.min_size == def.max_size).then_some(def.min_size),
+        TypeDef::DateTime(def) => Some(def.size),
+        TypeDef::Array(_) | TypeDef::Ref(_) => None,
+    }
+}
+
+/// Unique variant names for enumerations.
+pub(crate) fn variant_names<'a>(
+    states: impl Iterator<Item = (&'a String, &'a String)>,
+) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for (name, edt) in states {
+        let mut variant = pascal_ident(name, 'V');
+        if names.contains(&variant) {
+            variant.push_str(edt.trim_start_matches("0x"));
+        }
+        names.push(variant);
+    }
+    names
+}
+
+/// Converts a hex string like `0x0130` into a byte list like `0x01, 0x30`.
+pub(crate) fn byte_list(hex: &str, size: usize) -> String {
+    let value = u64::from_str_radix(hex.trim_start_matches("0x"), 16).unwrap_or_default();
+    let bytes = value.to_be_bytes();
+    bytes[8 - size.min(8)..]
+        .iter()
+        .map(|b| format!("{b:#04X}").replace("0X", "0x"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Parses a bit mask like `0b0000_0011`.
+fn parse_bitmask(mask: &str) -> u8 {
+    let digits: String = mask.trim_start_matches("0b").replace('_', "");
+    u8::from_str_radix(&digits, 2).unwrap_or_default()
+}
+
+/// Converts an MRA short name into `PascalCase`.
+pub(crate) fn pascal(name: &str) -> String {
+    let mut out = String::new();
+    let mut upper = true;
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            if upper {
+                out.push(c.to_ascii_uppercase());
+            } else {
+                out.push(c);
+            }
+            upper = false;
+        } else {
+            upper = true;
+        }
+    }
+    out
+}
+
+/// Converts an MRA short name into the name of a generated type.
+pub(crate) fn type_name(name: &str) -> String {
+    pascal_ident(name, 'T')
+}
+
+/// Converts an MRA short name into a `PascalCase` identifier,
+/// prefixed with `prefix` if it is empty or starts with a digit.
+pub(crate) fn pascal_ident(name: &str, prefix: char) -> String {
+    let mut out = pascal(name);
+    if out.is_empty() || out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, prefix);
+    }
+    if out == "Self" {
+        out.push_str("Value");
+    }
+    out
+}
+
+/// Converts an MRA short name into a `snake_case` identifier.
+pub(crate) fn snake(name: &str) -> String {
+    let out = snake_case(name);
+    match out.as_str() {
+        // not allowed as raw identifiers
+        "self" | "super" | "crate" => format!("{out}_"),
+        _ if is_keyword(&out) => format!("r#{out}"),
+        _ => out,
+    }
+}
+
+/// Converts an MRA short name into `snake_case`, prefixed with `_` if it starts with a digit.
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if prev_lower {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+            prev_lower = false;
+        } else if c.is_ascii_alphanumeric() {
+            out.push(c);
+            prev_lower = true;
+        } else if !out.ends_with('_') {
+            out.push('_');
+            prev_lower = false;
+        }
+    }
+    if out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    out
+}
+
+fn is_keyword(name: &str) -> bool {
+    matches!(
+        name,
+        "as" | "async"
+            | "await"
+            | "break"
+            | "const"
+            | "continue"
+            | "dyn"
+            | "else"
+            | "enum"
+            | "extern"
+            | "false"
+            | "fn"
+            | "for"
+            | "if"
+            | "impl"
+            | "in"
+            | "let"
+            | "loop"
+            | "match"
+            | "mod"
+            | "move"
+            | "mut"
+            | "pub"
+            | "ref"
+            | "return"
+            | "static"
+            | "struct"
+            | "trait"
+            | "true"
+            | "try"
+            | "type"
+            | "unsafe"
+            | "use"
+            | "where"
+            | "while"
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fixture() -> mra::MraClass {
+        let prop = |epc: &str, name: &str, data: &str| {
+            format!(
+                r#"{{ "epc": "{epc}", "validRelease": {{ "from": "A", "to": "latest" }},
+                    "propertyName": {{ "ja": "", "en": "{name}" }}, "shortName": "{name}",
+                    "accessRule": {{ "get": "required", "set": "optional", "inf": "optional" }},
+                    "descriptions": {{ "ja": "", "en": "" }}, "data": {data} }}"#
+            )
+        };
+        let uint8 = r#"{ "type": "number", "format": "uint8" }"#;
+        let props = [
+            prop(
+                "0x80",
+                "operationStatus",
+                r#"{ "type": "state", "size": 1, "enum": [
+                    { "edt": "0x30", "name": "true", "descriptions": { "ja": "", "en": "ON" } },
+                    { "edt": "0x31", "name": "false", "descriptions": { "ja": "", "en": "OFF" } } ] }"#,
+            ),
+            prop(
+                "0xE0",
+                "history",
+                &format!(
+                    r#"{{ "type": "array", "itemSize": 0, "maxItems": 4, "items": {uint8} }}"#
+                ),
+            ),
+            prop(
+                "0xE1",
+                "pair",
+                &format!(
+                    r#"{{ "type": "object", "properties": [
+                        {{ "shortName": "self", "element": {uint8}, "elementName": {{ "ja": "", "en": "self" }} }},
+                        {{ "shortName": "type", "element": {uint8}, "elementName": {{ "ja": "", "en": "type" }} }} ] }}"#
+                ),
+            ),
+            prop(
+                "0xE2",
+                "3rdMode",
+                &format!(
+                    r#"{{ "oneOf": [ {uint8},
+                        {{ "type": "number", "format": "int16" }},
+                        {{ "type": "state", "size": 1, "enum": [
+                            {{ "edt": "0xFF", "name": "unknown", "descriptions": {{ "ja": "", "en": "Unknown" }} }} ] }} ] }}"#
+                ),
+            ),
+        ];
+        serde_json::from_str(&format!(
+            r#"{{ "eoj": "0x02F0", "validRelease": {{ "from": "A", "to": "latest" }},
+                "className": {{ "ja": "", "en": "3 phase meter" }},
+                "shortName": "3phaseMeter", "elProperties": [{}] }}"#,
+            props.join(",")
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn generated_code_compiles() {
+        let code = generate(&fixture());
+        assert!(code.contains("pub mod _3phase_meter {"));
+        assert!(code.contains("pub struct History(pub Vec<u8>);"));
+        assert!(code.contains("pub self_: PairSelf,"));
+        assert!(code.contains("pub r#type: PairType,"));
+        assert!(code.contains("pub enum T3rdMode {"));
+        assert!(code.contains("    Number0(T3rdModeNumber0),"));
+        assert!(code.contains("    Number1(T3rdModeNumber1),"));
+        assert!(code.contains("    State(T3rdModeState),"));
+
+        let dir = std::env::temp_dir().join(format!("typegen-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let src = dir.join("generated.rs");
+        std::fs::write(&src, code).unwrap();
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let output = std::process::Command::new(rustc)
+            .args([
+                "--edition",
+                "2021",
+                "--crate-type",
+                "lib",
+                "--emit",
+                "metadata",
+            ])
+            .arg("--out-dir")
+            .arg(&dir)
+            .arg(&src)
+            .output()
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(pascal("operationStatus"), "OperationStatus");
+        assert_eq!(pascal("number_0-100"), "Number0100");
+        assert_eq!(type_name("3rdMode"), "T3rdMode");
+        assert_eq!(type_name("self"), "SelfValue");
+        assert_eq!(
+            snake("lvSmartElectricEnergyMeter"),
+            "lv_smart_electric_energy_meter"
+        );
+        assert_eq!(snake("type"), "r#type");
+        assert_eq!(snake("self"), "self_");
+        assert_eq!(snake("3phase"), "_3phase");
+        assert_eq!(byte_list("0x0130", 2), "0x01, 0x30");
+        assert_eq!(parse_bitmask("0b00110000"), 0x30);
+    }
+}